@@ -0,0 +1,102 @@
+use crate::utils::parse::{lines, parse_value, ParseError};
+use crate::utils::solution::Solution;
+use std::collections::BTreeSet;
+
+/// The sum of the entries to find, which only by chance equals the year.
+const TARGET_SUM: u32 = 2020;
+
+fn parse_values(input: &str) -> Result<(Vec<u32>, BTreeSet<u32>), ParseError> {
+    let values: Vec<u32> = lines(input)
+        .map(|value| match parse_value(value)? {
+            val if val > TARGET_SUM => Err(ParseError::new(value, "Value exceeds the sum!")),
+            val => Ok(val),
+        })
+        .collect::<Result<_, _>>()?;
+    let inverses = values.iter().map(|val| TARGET_SUM - val).collect();
+    Ok((values, inverses))
+}
+
+fn solve_part_one(values: &[u32], inverses: &BTreeSet<u32>) -> u32 {
+    let inverse_val = values
+        .iter()
+        .find(|&&val| inverses.contains(&val))
+        .expect("Nothing found!");
+    let val = TARGET_SUM - inverse_val;
+    val * inverse_val
+}
+
+fn solve_part_two(values: &[u32], inverses: &BTreeSet<u32>) -> u32 {
+    let (first_val, second_val) = values
+        .iter()
+        .flat_map(|val| vec![val].into_iter().cycle().zip(values.iter()))
+        .find(|(first_val, second_val)| inverses.contains(&(*first_val + *second_val)))
+        .expect("Nothing found!");
+    let inverse_val = TARGET_SUM - (first_val + second_val);
+    first_val * second_val * inverse_val
+}
+
+pub type Input<'a> = (Vec<u32>, BTreeSet<u32>);
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_values(input)
+}
+
+pub fn part_one((values, inverses): &Input<'_>) -> u32 {
+    solve_part_one(values, inverses)
+}
+
+pub fn part_two((values, inverses): &Input<'_>) -> u32 {
+    solve_part_two(values, inverses)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 1;
+    const DATA_FILE: &'static str = "01_data.list";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The product of the two entries summing to 2020 is {}.",
+        "The product of the three entries summing to 2020 is {}.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> u32 {
+        self::part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> u32 {
+        self::part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1721\n979\n366\n299\n675\n1456";
+
+    #[test]
+    fn part1_examples() {
+        let (values, inverses) = parse_values(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&values, &inverses), 514579);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let (values, inverses) = parse_values(EXAMPLE).unwrap();
+        assert_eq!(solve_part_two(&values, &inverses), 241861950);
+    }
+
+    #[test]
+    fn rejects_values_above_2020() {
+        assert!(parse_values("2020\n2021").is_err());
+    }
+}
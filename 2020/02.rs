@@ -0,0 +1,161 @@
+use crate::utils::parse::{lines, parse_value, split_once, ParseError};
+use crate::utils::solution::Solution;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct PasswordPolicy {
+    first_constraint: usize,
+    second_constraint: usize,
+    search_character: char,
+    suggestion: String,
+}
+
+impl PasswordPolicy {
+    fn check_suggestion_part_one(&self) -> bool {
+        let search_character_count = self
+            .suggestion
+            .chars()
+            .filter(|character| *character == self.search_character)
+            .count();
+
+        (self.first_constraint..=self.second_constraint).contains(&search_character_count)
+    }
+
+    fn check_suggestion_part_two(&self) -> bool {
+        (self.suggestion.chars().nth(self.first_constraint - 1) == Some(self.search_character))
+            != (self.suggestion.chars().nth(self.second_constraint - 1)
+                == Some(self.search_character))
+    }
+}
+
+impl FromStr for PasswordPolicy {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (occurrences, elements) = split_once(s, " ")?;
+
+        let (first_occurrence, second_occurrence) = split_once(occurrences, "-")?;
+
+        let first_constraint = parse_value::<usize>(first_occurrence)?;
+        if first_constraint == 0 {
+            return Err(ParseError::new(first_occurrence, "Positions start at 1!"));
+        }
+
+        let second_constraint = parse_value::<usize>(second_occurrence)?;
+
+        let (search_element, suggestion) = split_once(elements, " ")?;
+
+        let search_character: char = match search_element.chars().next() {
+            Some(character) => character,
+            None => return Err(ParseError::new(search_element, "No search character!")),
+        };
+
+        let suggestion = suggestion.to_string();
+
+        Ok(PasswordPolicy {
+            first_constraint,
+            second_constraint,
+            search_character,
+            suggestion,
+        })
+    }
+}
+
+fn parse_policies(input: &str) -> Result<Vec<PasswordPolicy>, ParseError> {
+    lines(input).map(str::parse::<PasswordPolicy>).collect()
+}
+
+fn solve_part_one(policies: &[PasswordPolicy]) -> usize {
+    policies
+        .iter()
+        .filter(|policy| policy.check_suggestion_part_one())
+        .count()
+}
+
+fn solve_part_two(policies: &[PasswordPolicy]) -> usize {
+    policies
+        .iter()
+        .filter(|policy| policy.check_suggestion_part_two())
+        .count()
+}
+
+pub type Input<'a> = Vec<PasswordPolicy>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_policies(input)
+}
+
+pub fn part_one(policies: &Input<'_>) -> usize {
+    solve_part_one(policies)
+}
+
+pub fn part_two(policies: &Input<'_>) -> usize {
+    solve_part_two(policies)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 2;
+    const DATA_FILE: &'static str = "02_data.list";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "There are {} valid passwords according to the old scheme.",
+        "There are {} valid passwords.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(policies: &Self::Input<'_>) -> usize {
+        self::part_one(policies)
+    }
+
+    fn part_two(policies: &Self::Input<'_>) -> usize {
+        self::part_two(policies)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc";
+
+    #[test]
+    fn part1_examples() {
+        let policies = parse_policies(EXAMPLE).unwrap();
+        assert_eq!(
+            policies
+                .iter()
+                .map(PasswordPolicy::check_suggestion_part_one)
+                .collect::<Vec<_>>(),
+            vec![true, false, true]
+        );
+        assert_eq!(solve_part_one(&policies), 2);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let policies = parse_policies(EXAMPLE).unwrap();
+        assert_eq!(
+            policies
+                .iter()
+                .map(PasswordPolicy::check_suggestion_part_two)
+                .collect::<Vec<_>>(),
+            vec![true, false, false]
+        );
+        assert_eq!(solve_part_two(&policies), 1);
+    }
+
+    #[test]
+    fn rejects_position_zero() {
+        assert!("0-3 a: abcde".parse::<PasswordPolicy>().is_err());
+        assert!("1-3 abcde".parse::<PasswordPolicy>().is_err());
+    }
+}
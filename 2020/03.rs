@@ -0,0 +1,108 @@
+use crate::utils::grid::Grid;
+use crate::utils::parse::ParseError;
+use crate::utils::solution::Solution;
+
+fn parse_slope_map(input: &str) -> Result<Grid<bool>, ParseError> {
+    Grid::parse(input, |character| match character {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+fn count_trees(slope_map: &Grid<bool>, (x_slope, y_slope): (usize, usize)) -> usize {
+    (0..slope_map.height())
+        .step_by(y_slope)
+        .enumerate()
+        .filter(|(step, y)| *slope_map.get_wrapping((step * x_slope) as isize, *y as isize))
+        .count()
+}
+
+fn solve_part_one(slope_map: &Grid<bool>) -> usize {
+    count_trees(slope_map, (3, 1))
+}
+
+fn solve_part_two(slope_map: &Grid<bool>) -> u64 {
+    let slopes = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+    slopes
+        .into_iter()
+        .map(|slope| count_trees(slope_map, slope) as u64)
+        .product()
+}
+
+pub type Input<'a> = Grid<bool>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_slope_map(input)
+}
+
+pub fn part_one(slope_map: &Input<'_>) -> usize {
+    solve_part_one(slope_map)
+}
+
+pub fn part_two(slope_map: &Input<'_>) -> u64 {
+    solve_part_two(slope_map)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 3;
+    const DATA_FILE: &'static str = "03_data.map";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "There are {} trees on the slope.",
+        "The slope-product is {}.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = usize;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(slope_map: &Self::Input<'_>) -> usize {
+        self::part_one(slope_map)
+    }
+
+    fn part_two(slope_map: &Self::Input<'_>) -> u64 {
+        self::part_two(slope_map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn part1_examples() {
+        let slope_map = parse_slope_map(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&slope_map), 7);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let slope_map = parse_slope_map(EXAMPLE).unwrap();
+        assert_eq!(
+            [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)].map(|slope| count_trees(&slope_map, slope)),
+            [2, 7, 3, 4, 2]
+        );
+        assert_eq!(solve_part_two(&slope_map), 336);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::utils::parse::{blocks, split_once, ParseError};
+use crate::utils::solution::Solution;
+use std::collections::HashMap;
+
+fn parse_pass_batch(input: &str) -> Result<Vec<HashMap<&str, &str>>, ParseError> {
+    blocks(input)
+        .map(|pass| {
+            pass.split_whitespace()
+                .map(|entry| split_once(entry, ":"))
+                .collect()
+        })
+        .collect()
+}
+
+fn check_valid_keys(passport: &HashMap<&str, &str>) -> bool {
+    let required_fields = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    required_fields
+        .into_iter()
+        .all(|key| passport.contains_key(key))
+}
+
+fn check_range(passport: &HashMap<&str, &str>, field: &str, from: i32, to: i32) -> bool {
+    (from..=to).contains(
+        &passport
+            .get(field)
+            .unwrap_or_else(|| panic!("No {}-field!", field))
+            .parse::<i32>()
+            .unwrap_or(0),
+    )
+}
+
+fn check_height(passport: &HashMap<&str, &str>) -> bool {
+    let height_item = passport.get("hgt").expect("No hgt-field!");
+    if let Some(height) = height_item.strip_suffix("cm") {
+        (150..=193).contains(&height.parse::<i32>().unwrap_or(0))
+    } else if let Some(height) = height_item.strip_suffix("in") {
+        (59..=76).contains(&height.parse::<i32>().unwrap_or(0))
+    } else {
+        false
+    }
+}
+
+fn check_hair_color(passport: &HashMap<&str, &str>) -> bool {
+    let hair_color_item = passport.get("hcl").expect("No hcl-field!");
+    hair_color_item.len() == 7
+        && hair_color_item.starts_with('#')
+        && hair_color_item
+            .chars()
+            .skip(1)
+            .all(|digit| char::is_ascii_hexdigit(&digit))
+}
+
+fn check_eye_color(passport: &HashMap<&str, &str>) -> bool {
+    matches!(
+        *passport.get("ecl").expect("No ecl-field!"),
+        "amb" | "blu" | "brn" | "gry" | "grn" | "hzl" | "oth"
+    )
+}
+
+fn check_pass_id(passport: &HashMap<&str, &str>) -> bool {
+    let pass_id_item = passport.get("pid").expect("No pid-field!");
+    pass_id_item.len() == 9
+        && pass_id_item
+            .chars()
+            .all(|digit| char::is_ascii_digit(&digit))
+}
+
+fn check_valid_values(passport: &HashMap<&str, &str>) -> bool {
+    check_range(passport, "byr", 1920, 2002)
+        && check_range(passport, "iyr", 2010, 2020)
+        && check_range(passport, "eyr", 2020, 2030)
+        && check_height(passport)
+        && check_hair_color(passport)
+        && check_eye_color(passport)
+        && check_pass_id(passport)
+}
+
+fn solve_part_one(passports: &[HashMap<&str, &str>]) -> usize {
+    passports
+        .iter()
+        .filter(|passport| check_valid_keys(passport))
+        .count()
+}
+
+fn solve_part_two(passports: &[HashMap<&str, &str>]) -> usize {
+    passports
+        .iter()
+        .filter(|passport| check_valid_keys(passport))
+        .filter(|passport| check_valid_values(passport))
+        .count()
+}
+
+pub type Input<'a> = Vec<HashMap<&'a str, &'a str>>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_pass_batch(input)
+}
+
+pub fn part_one(passports: &Input<'_>) -> usize {
+    solve_part_one(passports)
+}
+
+pub fn part_two(passports: &Input<'_>) -> usize {
+    solve_part_two(passports)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 4;
+    const DATA_FILE: &'static str = "04_data.batch";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "There are {} key-valid passports.",
+        "There are {} valid passports.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(passports: &Self::Input<'_>) -> usize {
+        self::part_one(passports)
+    }
+
+    fn part_two(passports: &Self::Input<'_>) -> usize {
+        self::part_two(passports)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passport(fields: &[(&'static str, &'static str)]) -> HashMap<&'static str, &'static str> {
+        fields.iter().copied().collect()
+    }
+
+    const EXAMPLE: &str = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in";
+
+    #[test]
+    fn part1_examples() {
+        let passports = parse_pass_batch(EXAMPLE).unwrap();
+        assert_eq!(passports.len(), 4);
+        assert_eq!(solve_part_one(&passports), 2);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let invalid = parse_pass_batch(
+            "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007",
+        )
+        .unwrap();
+        assert_eq!(solve_part_two(&invalid), 0);
+
+        let valid = parse_pass_batch(
+            "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719",
+        )
+        .unwrap();
+        assert_eq!(solve_part_two(&valid), 4);
+    }
+
+    #[test]
+    fn field_examples() {
+        assert!(check_range(
+            &passport(&[("byr", "2002")]),
+            "byr",
+            1920,
+            2002
+        ));
+        assert!(!check_range(
+            &passport(&[("byr", "2003")]),
+            "byr",
+            1920,
+            2002
+        ));
+
+        assert!(check_height(&passport(&[("hgt", "60in")])));
+        assert!(check_height(&passport(&[("hgt", "190cm")])));
+        assert!(!check_height(&passport(&[("hgt", "190in")])));
+        assert!(!check_height(&passport(&[("hgt", "190")])));
+
+        assert!(check_hair_color(&passport(&[("hcl", "#123abc")])));
+        assert!(!check_hair_color(&passport(&[("hcl", "#123abz")])));
+        assert!(!check_hair_color(&passport(&[("hcl", "123abc")])));
+
+        assert!(check_eye_color(&passport(&[("ecl", "brn")])));
+        assert!(!check_eye_color(&passport(&[("ecl", "wat")])));
+
+        assert!(check_pass_id(&passport(&[("pid", "000000001")])));
+        assert!(!check_pass_id(&passport(&[("pid", "0123456789")])));
+    }
+
+    #[test]
+    fn rejects_entries_without_colon() {
+        assert!(parse_pass_batch("ecl:gry pid").is_err());
+    }
+}
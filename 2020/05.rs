@@ -0,0 +1,179 @@
+use crate::utils::parse::{lines, ParseError};
+use crate::utils::solution::Solution;
+use std::cmp::Ordering;
+use std::ops::Sub;
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub struct SeatPosition {
+    pub row: u32,
+    pub column: u32,
+}
+
+impl SeatPosition {
+    fn get_id(&self) -> u32 {
+        (self.row * 8) + self.column
+    }
+}
+
+impl Sub<u32> for &SeatPosition {
+    type Output = SeatPosition;
+
+    fn sub(self, other: u32) -> Self::Output {
+        let new_id = self.get_id() - other;
+        let column = new_id % 8;
+        let row = (new_id - column) / 8;
+        SeatPosition { row, column }
+    }
+}
+
+impl FromStr for SeatPosition {
+    type Err = ParseError;
+
+    fn from_str(seat: &str) -> Result<Self, Self::Err> {
+        let converted_seat: String = seat
+            .char_indices()
+            .map(|(index, position_specifier)| match position_specifier {
+                'B' | 'R' => Ok('1'),
+                'F' | 'L' => Ok('0'),
+                character => Err(ParseError::new(
+                    &seat[index..index + character.len_utf8()],
+                    "Character not specified!",
+                )),
+            })
+            .collect::<Result<_, _>>()?;
+        if converted_seat.len() != 10 {
+            return Err(ParseError::new(seat, "A seat consists of 10 characters!"));
+        }
+        let to_number = |binary: &str| {
+            u32::from_str_radix(binary, 2).map_err(|err| ParseError::new(seat, err.to_string()))
+        };
+        let row = to_number(&converted_seat[0..7])?;
+        let column = to_number(&converted_seat[7..10])?;
+        Ok(SeatPosition { row, column })
+    }
+}
+
+impl Ord for SeatPosition {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.get_id().cmp(&other.get_id())
+    }
+}
+
+impl PartialOrd for SeatPosition {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Eq for SeatPosition {}
+
+impl PartialEq for SeatPosition {
+    fn eq(&self, other: &Self) -> bool {
+        self.get_id() == other.get_id()
+    }
+}
+
+fn parse_seats(input: &str) -> Result<Vec<SeatPosition>, ParseError> {
+    let mut seats = lines(input)
+        .map(str::parse::<SeatPosition>)
+        .collect::<Result<Vec<SeatPosition>, ParseError>>()?;
+    seats.sort_unstable();
+    Ok(seats)
+}
+
+fn solve_part_one(seats: &[SeatPosition]) -> u32 {
+    let highest_seat = seats.last().expect("No highest seat found!");
+    highest_seat.get_id()
+}
+
+fn solve_part_two(seats: &[SeatPosition]) -> u32 {
+    let my_seat = seats
+        .iter()
+        .scan(0_u32, |last_id, current| {
+            let distance = current.get_id() - *last_id;
+            *last_id = current.get_id();
+            Some((distance, current))
+        })
+        .filter(|(distance, _seat)| *distance == 2_u32)
+        .map(|(_distance, seat)| seat - 1_u32)
+        .next()
+        .expect("No free seat!");
+    my_seat.get_id()
+}
+
+pub type Input<'a> = Vec<SeatPosition>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_seats(input)
+}
+
+pub fn part_one(seats: &Input<'_>) -> u32 {
+    solve_part_one(seats)
+}
+
+pub fn part_two(seats: &Input<'_>) -> u32 {
+    solve_part_two(seats)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 5;
+    const DATA_FILE: &'static str = "05_data.list";
+    const DESCRIPTIONS: [&'static str; 2] = ["The highest seat ID is {}.", "My seat ID is {}."];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(seats: &Self::Input<'_>) -> u32 {
+        self::part_one(seats)
+    }
+
+    fn part_two(seats: &Self::Input<'_>) -> u32 {
+        self::part_two(seats)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        let seats = [
+            ("FBFBBFFRLR", 44, 5, 357),
+            ("BFFFBBFRRR", 70, 7, 567),
+            ("FFFBBBFRRR", 14, 7, 119),
+            ("BBFFBBFRLL", 102, 4, 820),
+        ];
+        for (seat, row, column, id) in seats {
+            let position = seat.parse::<SeatPosition>().unwrap();
+            assert_eq!(
+                (position.row, position.column, position.get_id()),
+                (row, column, id)
+            );
+        }
+
+        let seats = parse_seats("FBFBBFFRLR\nBBFFBBFRLL\nBFFFBBFRRR").unwrap();
+        assert_eq!(solve_part_one(&seats), 820);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let seats = parse_seats("FFFFFFBLRR\nFFFFFFBLLL\nFFFFFFBLLR").unwrap();
+        assert_eq!(solve_part_two(&seats), 10);
+    }
+
+    #[test]
+    fn rejects_invalid_seats() {
+        assert!("FBFBBFFRL".parse::<SeatPosition>().is_err());
+        assert!("FBFBBFFRLX".parse::<SeatPosition>().is_err());
+    }
+}
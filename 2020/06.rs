@@ -0,0 +1,122 @@
+use crate::utils::parse::{blocks, lines, ParseError};
+use crate::utils::solution::Solution;
+
+/// Parses the groups, each of the forms of its members.
+fn parse_answers(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+    blocks(input)
+        .map(|group| {
+            lines(group)
+                .map(|form| {
+                    match form
+                        .char_indices()
+                        .find(|(_index, character)| !character.is_ascii_lowercase())
+                    {
+                        Some((index, character)) => Err(ParseError::new(
+                            &form[index..index + character.len_utf8()],
+                            "Answers are only given as a to z!",
+                        )),
+                        None => Ok(form),
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn count_anyone_answer(group: &[&str]) -> usize {
+    ('a'..='z')
+        .filter(|character: &char| group.iter().any(|form| form.contains(*character)))
+        .count()
+}
+
+fn solve_part_one(answers: &[Vec<&str>]) -> usize {
+    answers.iter().map(|group| count_anyone_answer(group)).sum()
+}
+
+fn count_everyone_answer(group: &[&str]) -> usize {
+    ('a'..='z')
+        .filter(|character: &char| group.iter().all(|form| form.contains(*character)))
+        .count()
+}
+
+fn solve_part_two(answers: &[Vec<&str>]) -> usize {
+    answers
+        .iter()
+        .map(|group| count_everyone_answer(group))
+        .sum()
+}
+
+pub type Input<'a> = Vec<Vec<&'a str>>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_answers(input)
+}
+
+pub fn part_one(answers: &Input<'_>) -> usize {
+    solve_part_one(answers)
+}
+
+pub fn part_two(answers: &Input<'_>) -> usize {
+    solve_part_two(answers)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 6;
+    const DATA_FILE: &'static str = "06_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The count of questions anyone answered summed up is {}.",
+        "The count of questions everyone answered summed up is {}.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(answers: &Self::Input<'_>) -> usize {
+        self::part_one(answers)
+    }
+
+    fn part_two(answers: &Self::Input<'_>) -> usize {
+        self::part_two(answers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\n\na\nb\nc\n\nab\nac\n\na\na\na\na\n\nb";
+
+    #[test]
+    fn part1_examples() {
+        let answers = parse_answers(EXAMPLE).unwrap();
+        assert_eq!(
+            answers
+                .iter()
+                .map(|group| count_anyone_answer(group))
+                .collect::<Vec<_>>(),
+            vec![3, 3, 3, 1, 1]
+        );
+        assert_eq!(solve_part_one(&answers), 11);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let answers = parse_answers(EXAMPLE).unwrap();
+        assert_eq!(
+            answers
+                .iter()
+                .map(|group| count_everyone_answer(group))
+                .collect::<Vec<_>>(),
+            vec![3, 0, 1, 1, 1]
+        );
+        assert_eq!(solve_part_two(&answers), 6);
+    }
+}
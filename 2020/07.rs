@@ -0,0 +1,201 @@
+use crate::utils::parse::{lines, parse_value, split_once, ParseError};
+use crate::utils::solution::Solution;
+use crate::utils::unique::*;
+use std::collections::BTreeMap;
+
+type Tree = BTreeMap<String, Vec<(u32, String)>>;
+type ReverseTree = BTreeMap<String, Vec<String>>;
+/// A bag with the quantities of the bags directly inside it.
+type Rule<'a> = (&'a str, Vec<(u32, &'a str)>);
+
+fn parse_rule(rule: &str) -> Result<Rule<'_>, ParseError> {
+    let (parent, children) = split_once(rule, " contain ")?;
+    let parent = parent
+        .strip_suffix(" bags")
+        .ok_or_else(|| ParseError::new(parent, "Missing ` bags`!"))?;
+    if children == "no other bags." {
+        return Ok((parent, Vec::new()));
+    }
+    let children = children
+        .strip_suffix('.')
+        .ok_or_else(|| ParseError::new(&children[children.len()..], "Missing `.`!"))?;
+    let children = children
+        .split(", ")
+        .map(|child| {
+            let (quantity, name) = split_once(child, " ")?;
+            let name = name
+                .strip_suffix(" bags")
+                .or_else(|| name.strip_suffix(" bag"))
+                .ok_or_else(|| ParseError::new(name, "Missing ` bag`!"))?;
+            Ok((parse_value(quantity)?, name))
+        })
+        .collect::<Result<_, ParseError>>()?;
+    Ok((parent, children))
+}
+
+fn parse_rules(input: &str) -> Result<Tree, ParseError> {
+    lines(input)
+        .map(|rule| {
+            let (parent, children) = parse_rule(rule)?;
+            Ok((
+                parent.to_string(),
+                children
+                    .into_iter()
+                    .map(|(quantity, name)| (quantity, name.to_string()))
+                    .collect(),
+            ))
+        })
+        .collect()
+}
+
+fn reverse_rules(rules: &Tree) -> ReverseTree {
+    rules
+        .iter()
+        .fold(BTreeMap::new(), |mut reverse_rules, (parent, children)| {
+            children.iter().for_each(|(_quantity, child)| {
+                reverse_rules
+                    .entry(child.clone())
+                    .or_insert_with(Vec::new)
+                    .push(parent.clone())
+            });
+            reverse_rules
+        })
+}
+
+fn count_distinct_outer_layers(rules: &ReverseTree, pattern: &str, outers: &mut Vec<String>) {
+    let mut patterns = vec![pattern];
+    while let Some(pattern) = patterns.pop() {
+        if let Some(parents) = rules.get(pattern) {
+            parents.iter().for_each(|parent| {
+                outers.push(parent.to_string());
+                patterns.push(parent)
+            });
+        }
+    }
+}
+
+fn solve_part_one(rules: &ReverseTree) -> usize {
+    let mut shiny_gold_possibilities = Vec::new();
+    count_distinct_outer_layers(rules, "shiny gold", &mut shiny_gold_possibilities);
+    shiny_gold_possibilities.into_iter().unique().count()
+}
+
+/// Counts the bags inside of a bag on a work stack, where every bag is kept with
+/// how many of it there are in total.
+fn count_inner_bags(rules: &Tree, pattern: &str) -> u32 {
+    let mut count = 0;
+    let mut bags = vec![(1, pattern)];
+    while let Some((outer_quantity, name)) = bags.pop() {
+        for (quantity, inner) in rules.get(name).into_iter().flatten() {
+            count += outer_quantity * quantity;
+            bags.push((outer_quantity * quantity, inner));
+        }
+    }
+    count
+}
+
+fn solve_part_two(rules: &Tree) -> u32 {
+    count_inner_bags(rules, "shiny gold")
+}
+
+pub type Input<'a> = (ReverseTree, Tree);
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    let rules = parse_rules(input)?;
+    Ok((reverse_rules(&rules), rules))
+}
+
+pub fn part_one((reverse_rules, _rules): &Input<'_>) -> usize {
+    solve_part_one(reverse_rules)
+}
+
+pub fn part_two((_reverse_rules, rules): &Input<'_>) -> u32 {
+    solve_part_two(rules)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 7;
+    const DATA_FILE: &'static str = "07_data.rules";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The number of bag colors that can eventually contain at least one shiny gold bag is {}.",
+        "The shiny gold bag has to contain {} bags.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> usize {
+        self::part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> u32 {
+        self::part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.";
+
+    #[test]
+    fn parses_rules() {
+        assert_eq!(
+            parse_rule("muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.").unwrap(),
+            ("muted yellow", vec![(2, "shiny gold"), (9, "faded blue")])
+        );
+        assert_eq!(
+            parse_rule("faded blue bags contain no other bags.").unwrap(),
+            ("faded blue", vec![])
+        );
+        assert!(parse_rule("faded blue bags contain 2 shiny gold bags").is_err());
+    }
+
+    #[test]
+    fn part1_examples() {
+        let rules = reverse_rules(&parse_rules(EXAMPLE).unwrap());
+        let mut outers = Vec::new();
+        count_distinct_outer_layers(&rules, "bright white", &mut outers);
+        outers.sort_unstable();
+        assert_eq!(outers, vec!["dark orange", "light red"]);
+        assert_eq!(solve_part_one(&rules), 4);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let rules = parse_rules(EXAMPLE).unwrap();
+        assert_eq!(count_inner_bags(&rules, "dark olive"), 7);
+        assert_eq!(solve_part_two(&rules), 32);
+
+        let rules = parse_rules(
+            "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.",
+        )
+        .unwrap();
+        assert_eq!(solve_part_two(&rules), 126);
+    }
+}
@@ -0,0 +1,278 @@
+use crate::utils::parse::{lines, parse_value, split_once, ParseError};
+use crate::utils::random::Rng;
+use crate::utils::solution::{Generator, Solution};
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    NoOperation(i64),
+    Jump(i64),
+    Accumulate(i64),
+}
+
+impl Instruction {
+    fn is_control_flow(&self) -> bool {
+        !matches!(self, Instruction::Accumulate(_))
+    }
+
+    fn negate_control_flow(&mut self) {
+        match self {
+            Instruction::NoOperation(val) => *self = Instruction::Jump(*val),
+            Instruction::Jump(val) => *self = Instruction::NoOperation(*val),
+            _ => panic!("Can only negate NoOP & Jump!"),
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (operation, argument) = split_once(s, " ")?;
+
+        let number: i64 = parse_value(argument)?;
+
+        match operation {
+            "nop" => Ok(Instruction::NoOperation(number)),
+            "jmp" => Ok(Instruction::Jump(number)),
+            "acc" => Ok(Instruction::Accumulate(number)),
+            _ => Err(ParseError::new(operation, "Unknown instruction!")),
+        }
+    }
+}
+
+/// Written like it's parsed, e.g. `jmp -3`.
+impl Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::NoOperation(number) => write!(f, "nop {:+}", number),
+            Instruction::Jump(number) => write!(f, "jmp {:+}", number),
+            Instruction::Accumulate(number) => write!(f, "acc {:+}", number),
+        }
+    }
+}
+
+fn parse_asm(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    lines(input).map(str::parse::<Instruction>).collect()
+}
+
+#[derive(Debug)]
+enum RuntimeError {
+    InfiniteLoop(Vec<usize>, i64),
+    SegmentationFault(usize),
+}
+
+fn run(instructions: &[Instruction]) -> Result<i64, RuntimeError> {
+    let return_instruction = instructions.len();
+    let mut instruction_stack: Vec<usize> = Vec::new();
+    let mut accumulator = 0_i64;
+    let mut instruction_pointer = 0_usize;
+    loop {
+        if instruction_pointer == return_instruction {
+            break;
+        }
+        if instruction_stack.contains(&instruction_pointer) {
+            return Err(RuntimeError::InfiniteLoop(instruction_stack, accumulator));
+        }
+        let instruction = match instructions.get(instruction_pointer) {
+            Some(instruction) => instruction,
+            None => return Err(RuntimeError::SegmentationFault(instruction_pointer)),
+        };
+        instruction_stack.push(instruction_pointer);
+        match instruction {
+            Instruction::NoOperation(_) => instruction_pointer += 1,
+            Instruction::Jump(offset) => {
+                instruction_pointer = (instruction_pointer as i128 + *offset as i128) as usize
+            }
+            Instruction::Accumulate(value) => {
+                accumulator += value;
+                instruction_pointer += 1
+            }
+        }
+    }
+    Ok(accumulator)
+}
+
+impl RuntimeError {
+    /// Why a program, which should loop, doesn't.
+    fn describe(result: Result<i64, RuntimeError>) -> String {
+        match result {
+            Ok(_accumulator) => "No infinite loop detected!".to_string(),
+            Err(RuntimeError::SegmentationFault(instruction_pointer)) => {
+                format!("Jumped out of the program to {}!", instruction_pointer)
+            }
+            Err(RuntimeError::InfiniteLoop(..)) => "Looped infinitely!".to_string(),
+        }
+    }
+}
+
+fn solve_part_one(instructions: &[Instruction]) -> Result<i64, String> {
+    match run(instructions) {
+        Err(RuntimeError::InfiniteLoop(_stacktrace, last_accumulator)) => Ok(last_accumulator),
+        result => Err(RuntimeError::describe(result)),
+    }
+}
+
+fn backtrace_infinite_loop(
+    instructions: &[Instruction],
+    stacktrace: &[usize],
+) -> Result<(usize, i64), ()> {
+    for instruction_index in stacktrace.iter().rev() {
+        if !instructions[*instruction_index].is_control_flow() {
+            continue;
+        }
+        let mut updated_instructions: Vec<Instruction> = instructions.to_vec();
+        updated_instructions[*instruction_index].negate_control_flow();
+        if let Ok(accumulator) = run(&updated_instructions) {
+            return Ok((*instruction_index, accumulator));
+        }
+    }
+    Err(())
+}
+
+fn solve_part_two(instructions: &[Instruction]) -> Result<i64, String> {
+    let stacktrace = match run(instructions) {
+        Err(RuntimeError::InfiniteLoop(stacktrace, _last_accumulator)) => stacktrace,
+        result => return Err(RuntimeError::describe(result)),
+    };
+    let (_negated_instruction, accumulator_result) =
+        backtrace_infinite_loop(instructions, &stacktrace)
+            .map_err(|()| "There is no way of stopping the infinite loop!".to_string())?;
+    Ok(accumulator_result)
+}
+
+/// Generates a program of `size` instructions, where exactly one jump has to be
+/// negated to stop the infinite loop.
+///
+/// Before the corrupted jump, every instruction (whether negated or not) leads
+/// forward to at most the corrupted jump, which loops back. Behind it, every
+/// instruction leads forward to at most the end of the program.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let length = size.max(2) as i64;
+    let corrupted = rng.range(1..length);
+    let mut instructions = Vec::new();
+    for index in 0..length {
+        let end = if index < corrupted { corrupted } else { length };
+        let instruction = if index == corrupted {
+            Instruction::Jump(rng.range(0..corrupted) - corrupted)
+        } else {
+            match rng.below(4) {
+                0 => Instruction::Jump(rng.range(1..end - index + 1)),
+                1 if index < corrupted => {
+                    Instruction::NoOperation(rng.range(-index..end - index + 1))
+                }
+                1 => Instruction::NoOperation(rng.range(-10..11)),
+                _ => Instruction::Accumulate(rng.range(-50..51)),
+            }
+        };
+        instructions.push(instruction.to_string());
+    }
+    instructions.join("\n")
+}
+
+pub type Input<'a> = Vec<Instruction>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_asm(input)
+}
+
+pub fn part_one(instructions: &Input<'_>) -> Result<i64, String> {
+    solve_part_one(instructions)
+}
+
+pub fn part_two(instructions: &Input<'_>) -> Result<i64, String> {
+    solve_part_two(instructions)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 8;
+    const DATA_FILE: &'static str = "08_data.asm";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "There was an infinite loop with the last value {}.",
+        "Negating the looping instruction, there is the result {}.",
+    ];
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    type Input<'a> = Input<'a>;
+    type PartOne = Result<i64, String>;
+    type PartTwo = Result<i64, String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(instructions: &Self::Input<'_>) -> Result<i64, String> {
+        self::part_one(instructions)
+    }
+
+    fn part_two(instructions: &Self::Input<'_>) -> Result<i64, String> {
+        self::part_two(instructions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "nop +0\nacc +1\njmp +4\nacc +3\njmp -3\nacc -99\nacc +1\njmp -4\nacc +6";
+
+    #[test]
+    fn part1_examples() {
+        let instructions = parse_asm(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&instructions), Ok(5));
+    }
+
+    #[test]
+    fn part2_examples() {
+        let instructions = parse_asm(EXAMPLE).unwrap();
+        let stacktrace = match run(&instructions) {
+            Err(RuntimeError::InfiniteLoop(stacktrace, _last_accumulator)) => stacktrace,
+            _ => panic!("No infinite loop detected!"),
+        };
+        assert_eq!(stacktrace, vec![0, 1, 2, 6, 7, 3, 4]);
+        assert_eq!(
+            backtrace_infinite_loop(&instructions, &stacktrace),
+            Ok((7, 8))
+        );
+        assert_eq!(solve_part_two(&instructions), Ok(8));
+    }
+
+    #[test]
+    fn detects_segmentation_faults() {
+        let instructions = parse_asm("nop +0\njmp +5").unwrap();
+        assert!(matches!(
+            run(&instructions),
+            Err(RuntimeError::SegmentationFault(6))
+        ));
+        assert_eq!(
+            solve_part_one(&instructions),
+            Err("Jumped out of the program to 6!".to_string())
+        );
+        let instructions = parse_asm("acc +1\nacc +1").unwrap();
+        assert_eq!(
+            solve_part_two(&instructions),
+            Err("No infinite loop detected!".to_string())
+        );
+    }
+
+    #[test]
+    fn generates_one_corrupted_jump() {
+        for (seed, size) in [(0, 2), (1, 10), (2, 100), (3, 300)] {
+            let instructions = parse(&generate(&mut Rng::new(seed), size)).unwrap();
+            assert_eq!(instructions.len(), size);
+            let fixes = (0..instructions.len())
+                .filter(|index| instructions[*index].is_control_flow())
+                .filter_map(|index| {
+                    let mut fixed = instructions.clone();
+                    fixed[index].negate_control_flow();
+                    run(&fixed).ok()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(fixes, vec![part_two(&instructions).unwrap()]);
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::utils::parse::{lines, parse_value, ParseError};
+use crate::utils::solution::Solution;
+
+fn parse_encrypted(input: &str) -> Result<Vec<u64>, ParseError> {
+    lines(input).map(parse_value).collect()
+}
+
+const PREAMBLE_SIZE: usize = 25;
+
+fn get_first_invalid(encrypted: &[u64], preamble_size: usize) -> Option<(usize, u64)> {
+    for (step, num) in encrypted.iter().enumerate().skip(preamble_size) {
+        let leading_numbers = &encrypted[step - preamble_size..step];
+        if !leading_numbers
+            .iter()
+            .filter(|previous_number| *previous_number <= num)
+            .any(|previous_number| leading_numbers.contains(&(num - previous_number)))
+        {
+            return Some((step, *num));
+        }
+    }
+    None
+}
+
+fn solve_part_one(encrypted: &[u64]) -> u64 {
+    let (_position, invalid) =
+        get_first_invalid(encrypted, PREAMBLE_SIZE).expect("There is no invalid position.");
+    invalid
+}
+
+fn find_weakness(encrypted: &[u64], invalid: u64) -> Option<u64> {
+    let mut sequence: std::collections::VecDeque<u64> = std::collections::VecDeque::new();
+    for num in encrypted.iter() {
+        sequence.push_back(*num);
+        let mut sequence_sum = sequence.iter().sum();
+        while invalid < sequence_sum {
+            sequence.pop_front();
+            sequence_sum = sequence.iter().sum();
+        }
+        if invalid == sequence_sum {
+            return Some(
+                sequence.iter().min().expect("Empty sequence!")
+                    + sequence.iter().max().expect("Empty sequence!"),
+            );
+        }
+    }
+    None
+}
+
+fn solve_part_two(encrypted: &[u64]) -> u64 {
+    let (_position, first_invalid) =
+        get_first_invalid(encrypted, PREAMBLE_SIZE).expect("There is no invalid position.");
+    find_weakness(encrypted, first_invalid).expect("There is no weakness.")
+}
+
+pub type Input<'a> = Vec<u64>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_encrypted(input)
+}
+
+pub fn part_one(encrypted: &Input<'_>) -> u64 {
+    solve_part_one(encrypted)
+}
+
+pub fn part_two(encrypted: &Input<'_>) -> u64 {
+    solve_part_two(encrypted)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 9;
+    const DATA_FILE: &'static str = "09_data.enc";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The scheme is not fulfilled with value {}.",
+        "The weakness is {}.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(encrypted: &Self::Input<'_>) -> u64 {
+        self::part_one(encrypted)
+    }
+
+    fn part_two(encrypted: &Self::Input<'_>) -> u64 {
+        self::part_two(encrypted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+35\n20\n15\n25\n47\n40\n62\n55\n65\n95\n102\n117\n150\n182\n127\n219\n299\n277\n309\n576";
+
+    #[test]
+    fn part1_examples() {
+        let mut encrypted: Vec<u64> = (1..=25).rev().collect();
+        encrypted.push(26);
+        assert_eq!(get_first_invalid(&encrypted, 25), None);
+        encrypted.push(100);
+        assert_eq!(get_first_invalid(&encrypted, 25), Some((26, 100)));
+
+        let encrypted = parse_encrypted(EXAMPLE).unwrap();
+        assert_eq!(get_first_invalid(&encrypted, 5), Some((14, 127)));
+    }
+
+    #[test]
+    fn part2_examples() {
+        let encrypted = parse_encrypted(EXAMPLE).unwrap();
+        assert_eq!(find_weakness(&encrypted, 127), Some(62));
+        assert_eq!(find_weakness(&encrypted, 1), None);
+    }
+}
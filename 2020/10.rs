@@ -0,0 +1,157 @@
+use crate::utils::bigint::Number;
+use crate::utils::parse::{lines, parse_value, ParseError};
+use crate::utils::solution::Solution;
+use std::collections::BTreeMap;
+
+fn parse_adapters(input: &str) -> Result<Vec<u64>, ParseError> {
+    let mut adapters = lines(input)
+        .map(parse_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    if adapters.is_empty() {
+        return Err(ParseError::new(input, "No adapters!"));
+    }
+
+    adapters.sort_unstable();
+    Ok(adapters)
+}
+
+fn solve_part_one(adapters: &[u64]) -> u64 {
+    let diff_count = adapters
+        .iter()
+        .scan(0, |previous, &current| {
+            let difference = current - *previous;
+            *previous = current;
+            Some(difference)
+        })
+        .fold(BTreeMap::new(), |mut aggregator, difference| {
+            aggregator
+                .entry(difference)
+                .and_modify(|count| *count += 1)
+                .or_insert(1_u64);
+            aggregator
+        });
+    diff_count.get(&1).unwrap_or(&0) * (diff_count.get(&3).unwrap_or(&0) + 1)
+}
+
+/// Counts the chains from `last_value` to the last of the sorted adapters, which
+/// are built from the last adapter backwards. Their number grows exponentially,
+/// so it's counted as `N`.
+fn count_mutations<N: Number>(adapters: &[u64], last_value: u64) -> N {
+    let mut mutations = vec![N::from(0); adapters.len()];
+    for index in (0..adapters.len()).rev() {
+        mutations[index] = if index + 1 == adapters.len() {
+            N::from(1)
+        } else {
+            (index + 1..adapters.len())
+                .take_while(|next| adapters[*next] <= adapters[index] + 3)
+                .map(|next| mutations[next].clone())
+                .sum()
+        };
+    }
+    adapters
+        .iter()
+        .zip(mutations)
+        .take_while(|(adapter, _mutations)| **adapter <= last_value + 3)
+        .map(|(_adapter, mutations)| mutations)
+        .sum()
+}
+
+fn solve_part_two<N: Number>(adapters: &[u64]) -> N {
+    count_mutations(adapters, 0)
+}
+
+pub type Input<'a> = Vec<u64>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_adapters(input)
+}
+
+pub fn part_one(adapters: &Input<'_>) -> u64 {
+    solve_part_one(adapters)
+}
+
+/// Counts in an `u64` for the puzzle, or exactly as a `BigUint`.
+pub fn part_two<N: Number>(adapters: &Input<'_>) -> N {
+    solve_part_two(adapters)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 10;
+    const DATA_FILE: &'static str = "10_data.list";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The charging product is {}.",
+        "There are {} valid mutations.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(adapters: &Self::Input<'_>) -> u64 {
+        self::part_one(adapters)
+    }
+
+    fn part_two(adapters: &Self::Input<'_>) -> u64 {
+        self::part_two(adapters)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::bigint::BigUint;
+
+    const SMALL_EXAMPLE: &str = "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4";
+    const LARGE_EXAMPLE: &str = "\
+28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(solve_part_one(&parse_adapters(SMALL_EXAMPLE).unwrap()), 35);
+        assert_eq!(solve_part_one(&parse_adapters(LARGE_EXAMPLE).unwrap()), 220);
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(
+            solve_part_two::<u64>(&parse_adapters(SMALL_EXAMPLE).unwrap()),
+            8
+        );
+        assert_eq!(
+            solve_part_two::<u64>(&parse_adapters(LARGE_EXAMPLE).unwrap()),
+            19208
+        );
+    }
+
+    #[test]
+    fn counts_mutations() {
+        assert_eq!(count_mutations::<u64>(&[1, 2, 3], 0), 4);
+        assert_eq!(count_mutations::<u64>(&[3, 6, 9], 0), 1);
+        assert_eq!(count_mutations::<u64>(&[4], 0), 0);
+        assert_eq!(count_mutations::<u64>(&[1, 4], 0), 1);
+    }
+
+    #[test]
+    fn counts_mutations_exactly() {
+        let adapters = parse_adapters(SMALL_EXAMPLE).unwrap();
+        assert_eq!(part_two::<BigUint>(&adapters), BigUint::from(8_u32));
+        // The counts of consecutive adapters are the Tribonacci numbers.
+        let adapters = (1..=100).collect::<Vec<_>>();
+        assert_eq!(
+            part_two::<BigUint>(&adapters).to_string(),
+            "180396380815100901214157639"
+        );
+    }
+
+    #[test]
+    fn rejects_missing_adapters() {
+        assert!(parse_adapters("").is_err());
+    }
+}
@@ -0,0 +1,241 @@
+use crate::utils::grid::{Grid, Position, ADJACENT};
+use crate::utils::parse::ParseError;
+use crate::utils::solution::Solution;
+
+type Map = Grid<PositionState>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PositionState {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl PositionState {
+    pub fn is_occupied(&self) -> bool {
+        matches!(self, PositionState::Occupied)
+    }
+
+    pub fn is_seat(&self) -> bool {
+        !matches!(self, PositionState::Floor)
+    }
+
+    pub fn calculate_new<'a>(
+        &self,
+        count_occupied: &'a dyn Fn(&Map, Position) -> u32,
+        map: &Map,
+        position: Position,
+        disallowed_occupied: u32,
+        stabilized: &mut bool,
+    ) -> Self {
+        match self {
+            PositionState::Floor => PositionState::Floor,
+            PositionState::Empty => {
+                if (count_occupied)(map, position) == 0 {
+                    *stabilized = false;
+                    PositionState::Occupied
+                } else {
+                    PositionState::Empty
+                }
+            }
+            PositionState::Occupied => {
+                if (count_occupied)(map, position) >= disallowed_occupied {
+                    *stabilized = false;
+                    PositionState::Empty
+                } else {
+                    PositionState::Occupied
+                }
+            }
+        }
+    }
+}
+
+fn count_occupied_neighbors(map: &Map, position: Position) -> u32 {
+    map.neighbors_8(position)
+        .filter(|neighbor| map[*neighbor].is_occupied())
+        .count() as u32
+}
+
+fn count_occupied_axis(map: &Map, position: Position) -> u32 {
+    ADJACENT
+        .iter()
+        .filter(|direction| {
+            map.ray(position, **direction)
+                .map(|seen| &map[seen])
+                .find(|state| state.is_seat())
+                .is_some_and(|state| state.is_occupied())
+        })
+        .count() as u32
+}
+
+fn parse_map(input: &str) -> Result<Map, ParseError> {
+    Grid::parse(input, |character| match character {
+        '.' => Some(PositionState::Floor),
+        'L' => Some(PositionState::Empty),
+        '#' => Some(PositionState::Occupied),
+        _ => None,
+    })
+}
+
+struct BehaviourEngine<'a> {
+    map: Map,
+    count_occupied: &'a dyn Fn(&Map, Position) -> u32,
+    disallowed_occupied: u32,
+    stabilized: bool,
+}
+
+impl<'a> BehaviourEngine<'a> {
+    pub fn run_to_stabilized(&mut self) {
+        while !self.stabilized {
+            self.step();
+        }
+    }
+
+    fn step(&mut self) {
+        self.stabilized = true;
+        self.map = self.map.map(|position, state| {
+            state.calculate_new(
+                self.count_occupied,
+                &self.map,
+                position,
+                self.disallowed_occupied,
+                &mut self.stabilized,
+            )
+        });
+    }
+
+    pub fn get_all_occupied(&self) -> u32 {
+        self.map
+            .values()
+            .filter(|state| state.is_occupied())
+            .count() as u32
+    }
+}
+
+fn solve_part_one(map: &Map) -> u32 {
+    let mut engine = BehaviourEngine {
+        map: map.clone(),
+        count_occupied: &count_occupied_neighbors,
+        disallowed_occupied: 4,
+        stabilized: false,
+    };
+    engine.run_to_stabilized();
+    engine.get_all_occupied()
+}
+
+fn solve_part_two(map: &Map) -> u32 {
+    let mut engine = BehaviourEngine {
+        map: map.clone(),
+        count_occupied: &count_occupied_axis,
+        disallowed_occupied: 5,
+        stabilized: false,
+    };
+    engine.run_to_stabilized();
+    engine.get_all_occupied()
+}
+
+pub type Input<'a> = Map;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_map(input)
+}
+
+pub fn part_one(map: &Input<'_>) -> u32 {
+    solve_part_one(map)
+}
+
+pub fn part_two(map: &Input<'_>) -> u32 {
+    solve_part_two(map)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 11;
+    const DATA_FILE: &'static str = "11_data.map";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "There end up {} seats occupied (neighbor model).",
+        "There end up {} seats occupied (axis model).",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(map: &Self::Input<'_>) -> u32 {
+        self::part_one(map)
+    }
+
+    fn part_two(map: &Self::Input<'_>) -> u32 {
+        self::part_two(map)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL";
+
+    #[test]
+    fn part1_examples() {
+        let map = parse_map(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&map), 37);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let map = parse_map(EXAMPLE).unwrap();
+        assert_eq!(solve_part_two(&map), 26);
+    }
+
+    #[test]
+    fn counts_visible_seats() {
+        let map = parse_map(
+            "\
+.......#.
+...#.....
+.#.......
+.........
+..#L....#
+....#....
+.........
+#........
+...#.....",
+        )
+        .unwrap();
+        assert_eq!(count_occupied_axis(&map, (3, 4)), 8);
+        assert_eq!(count_occupied_neighbors(&map, (3, 4)), 2);
+
+        let map = parse_map(".............\n.L.L.#.#.#.#.\n.............").unwrap();
+        assert_eq!(count_occupied_axis(&map, (1, 1)), 0);
+
+        let map = parse_map(
+            "\
+.##.##.
+#.#.#.#
+##...##
+...L...
+##...##
+#.#.#.#
+.##.##.",
+        )
+        .unwrap();
+        assert_eq!(count_occupied_axis(&map, (3, 3)), 0);
+    }
+}
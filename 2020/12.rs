@@ -1,20 +1,9 @@
-use std::num::ParseIntError;
+use crate::utils::parse::{lines, parse_value, ParseError};
+use crate::utils::solution::Solution;
 use std::str::FromStr;
 
-#[derive(Debug)]
-pub enum ParseActionError {
-    UnknownAction,
-    ParseInt(ParseIntError),
-}
-
-impl From<ParseIntError> for ParseActionError {
-    fn from(err: ParseIntError) -> ParseActionError {
-        ParseActionError::ParseInt(err)
-    }
-}
-
-#[derive(Clone, Debug)]
-enum Direction {
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Direction {
     North,
     East,
     South,
@@ -50,8 +39,8 @@ impl Direction {
     }
 }
 
-#[derive(Clone, Debug)]
-enum Action {
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Action {
     Move(Direction, u32),
     Left(u32),
     Right(u32),
@@ -59,16 +48,22 @@ enum Action {
 }
 
 impl FromStr for Action {
-    type Err = ParseActionError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut elements = s.chars();
-
-        let action = match elements.next() {
+        let action = match s.chars().next() {
             Some(character) => character,
-            None => return Err(ParseActionError::UnknownAction),
+            None => return Err(ParseError::new(s, "Missing action!")),
         };
-        let number: u32 = elements.collect::<String>().parse()?;
+        let argument = &s[action.len_utf8()..];
+        let number: u32 = parse_value(argument)?;
+
+        if matches!(action, 'L' | 'R') && !number.is_multiple_of(90) {
+            return Err(ParseError::new(
+                argument,
+                "Turns have to be multiples of 90 degrees!",
+            ));
+        }
 
         match action {
             'N' => Ok(Action::Move(Direction::North, number)),
@@ -78,13 +73,13 @@ impl FromStr for Action {
             'L' => Ok(Action::Left(number % 360)),
             'R' => Ok(Action::Right(number % 360)),
             'F' => Ok(Action::Forward(number)),
-            _ => Err(ParseActionError::UnknownAction),
+            _ => Err(ParseError::new(&s[..action.len_utf8()], "Unknown action!")),
         }
     }
 }
 
-fn parse_actions(input: &str) -> Result<Vec<Action>, ParseActionError> {
-    input.lines().map(str::parse::<Action>).collect()
+fn parse_actions(input: &str) -> Result<Vec<Action>, ParseError> {
+    lines(input).map(str::parse::<Action>).collect()
 }
 
 fn manhattan_distance(actions: &[Action], mut current_view: Direction) -> u32 {
@@ -105,15 +100,11 @@ fn manhattan_distance(actions: &[Action], mut current_view: Direction) -> u32 {
             _ => unreachable!(),
         }
     }
-    north_distance.abs() as u32 + east_distance.abs() as u32
+    north_distance.unsigned_abs() + east_distance.unsigned_abs()
 }
 
-fn solve_part_one(actions: &[Action]) {
-    let distance = manhattan_distance(actions, Direction::East);
-    println!(
-        "The Manhattan distance between that location and the ship's starting position is {}.",
-        distance
-    );
+fn solve_part_one(actions: &[Action]) -> u32 {
+    manhattan_distance(actions, Direction::East)
 }
 
 fn manhattan_distance_with_waypoints(
@@ -171,24 +162,83 @@ fn manhattan_distance_with_waypoints(
             },
         }
     }
-    north_distance.abs() as u32 + east_distance.abs() as u32
+    north_distance.unsigned_abs() + east_distance.unsigned_abs()
+}
+
+fn solve_part_two(actions: &[Action]) -> u32 {
+    manhattan_distance_with_waypoints(actions, 1, 10)
+}
+
+pub type Input<'a> = Vec<Action>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_actions(input)
+}
+
+pub fn part_one(actions: &Input<'_>) -> u32 {
+    solve_part_one(actions)
 }
 
-fn solve_part_two(actions: &[Action]) {
-    let distance = manhattan_distance_with_waypoints(actions, 1, 10);
-    println!(
+pub fn part_two(actions: &Input<'_>) -> u32 {
+    solve_part_two(actions)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 12;
+    const DATA_FILE: &'static str = "12_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The Manhattan distance between that location and the ship's starting position is {}.",
         "The Manhattan distance between that location and the ship's starting position is {} using waypoints.",
-        distance
-    );
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(actions: &Self::Input<'_>) -> u32 {
+        self::part_one(actions)
+    }
+
+    fn part_two(actions: &Self::Input<'_>) -> u32 {
+        self::part_two(actions)
+    }
 }
 
-fn main() -> Result<(), ParseActionError> {
-    let input = include_str!("12_data.txt");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let actions = parse_actions(input)?;
+    const EXAMPLE: &str = "F10\nN3\nF7\nR90\nF11";
 
-    solve_part_one(&actions);
-    solve_part_two(&actions);
+    #[test]
+    fn part1_examples() {
+        let actions = parse_actions(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&actions), 25);
+    }
 
-    Ok(())
+    #[test]
+    fn part2_examples() {
+        let actions = parse_actions(EXAMPLE).unwrap();
+        assert_eq!(solve_part_two(&actions), 286);
+    }
+
+    #[test]
+    fn turns_both_ways() {
+        let mut direction = Direction::East;
+        direction.left(270);
+        assert!(matches!(direction, Direction::South));
+        direction.right(180);
+        assert!(matches!(direction, Direction::North));
+
+        let actions = parse_actions("L90\nF1\nR270\nF2").unwrap();
+        assert_eq!(manhattan_distance_with_waypoints(&actions, 1, 10), 29);
+        assert!("R45".parse::<Action>().is_err());
+    }
 }
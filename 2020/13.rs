@@ -0,0 +1,264 @@
+use crate::utils::bigint::BigUint;
+use crate::utils::maths::{chinese_remainder, gcd, primes_in, CongruenceError, ModInt};
+use crate::utils::parse::{lines, parse_value, ParseError};
+use crate::utils::random::Rng;
+use crate::utils::solution::{Generator, Solution};
+
+fn parse_plan(input: &str) -> Result<(u32, Vec<(usize, u32)>), ParseError> {
+    let mut plan = lines(input);
+    let (arrival, ids) = plan
+        .next()
+        .zip(plan.next())
+        .ok_or_else(|| ParseError::new(&input[input.len()..], "Missing the bus IDs!"))?;
+    let bus_ids = ids
+        .split(',')
+        .enumerate()
+        .filter(|(_index, id)| *id != "x")
+        .map(|(index, id)| match parse_value(id)? {
+            0 => Err(ParseError::new(id, "Bus IDs have to be positive!")),
+            id => Ok((index, id)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    if bus_ids.is_empty() {
+        return Err(ParseError::new(ids, "No bus IDs!"));
+    }
+    Ok((parse_value(arrival)?, bus_ids))
+}
+
+/// The bus departing next with the minutes waited for it, where a bus departing
+/// right on arrival is waited for its full period.
+fn get_next_bus(arrival: u32, bus_ids: &[u32]) -> Option<(u32, u32)> {
+    bus_ids
+        .iter()
+        .map(|id| {
+            let departed_since = ModInt::new(arrival.into(), (*id).into());
+            (*id, id - departed_since.value() as u32)
+        })
+        .min_by_key(|(_id, waiting_time)| *waiting_time)
+}
+
+fn solve_part_one(arrival: u32, bus_ids: &[(usize, u32)]) -> Result<u32, String> {
+    let (bus_id, waiting_time) = get_next_bus(
+        arrival,
+        &bus_ids.iter().map(|(_index, id)| *id).collect::<Vec<_>>(),
+    )
+    .ok_or("No bus departs!")?;
+    Ok(bus_id * waiting_time)
+}
+
+/// Merges the congruences one bus at a time like `chinese_remainder`, but with
+/// a `BigUint` timestamp and period, so nothing overflows.
+fn get_exact_bus_row(bus_ids: &[(usize, u32)]) -> Result<BigUint, CongruenceError> {
+    let (mut timestamp, mut period) = (BigUint::zero(), BigUint::from(1_u32));
+    for (index, id) in bus_ids {
+        let (_, period_residue) = period.div_rem_u32(*id);
+        let (_, timestamp_residue) = timestamp.div_rem_u32(*id);
+        let id = u64::from(*id);
+        // The timestamp advances by some multiple of the period until this bus fits.
+        let missing = -ModInt::new(*index as u64, id) - ModInt::new(timestamp_residue.into(), id);
+        let divisor = gcd(period_residue.into(), id);
+        if !missing.value().is_multiple_of(divisor) {
+            return Err(CongruenceError::Inconsistent);
+        }
+        let modulus = id / divisor;
+        let steps = ModInt::new(missing.value() / divisor, modulus)
+            / ModInt::new(u64::from(period_residue) / divisor, modulus);
+        timestamp += &(&period * &BigUint::from(steps.value()));
+        period = period * BigUint::from(modulus);
+    }
+    Ok(timestamp)
+}
+
+/// The first timestamp, where every bus departs as many minutes later as its index.
+fn get_bus_row(bus_ids: &[(usize, u32)]) -> Result<BigUint, CongruenceError> {
+    match chinese_remainder(
+        &bus_ids
+            .iter()
+            .map(|(index, id)| (*id as i128, -(*index as i128)))
+            .collect::<Vec<_>>(),
+    ) {
+        Ok((timestamp, _period)) => Ok((timestamp as u128).into()),
+        Err(CongruenceError::Overflow) => get_exact_bus_row(bus_ids),
+        Err(error) => Err(error),
+    }
+}
+
+fn solve_part_two(bus_ids: &[(usize, u32)]) -> Result<BigUint, String> {
+    get_bus_row(bus_ids).map_err(|_error| "The buses never depart in a row!".to_string())
+}
+
+/// Generates a schedule of `size` slots, where distinct primes below 1000 are
+/// the bus IDs, as long as their product stays small enough for the timestamp.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut primes = primes_in(5..1000);
+    rng.shuffle(&mut primes);
+    let mut slots = vec!["x".to_string(); size.max(1)];
+    // The first slot always has a bus, the others are taken in a random order.
+    let mut taken = (1..slots.len()).collect::<Vec<_>>();
+    rng.shuffle(&mut taken);
+    taken.insert(0, 0);
+    let mut product = 1_u64;
+    for (slot, prime) in taken.into_iter().zip(primes) {
+        product = match product.checked_mul(prime) {
+            Some(product) if product < 1_000_000_000_000 => product,
+            _ => break,
+        };
+        slots[slot] = prime.to_string();
+    }
+    format!("{}\n{}", rng.range(0..1_000_000), slots.join(","))
+}
+
+pub type Input<'a> = (u32, Vec<(usize, u32)>);
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_plan(input)
+}
+
+pub fn part_one((arrival, bus_ids): &Input<'_>) -> Result<u32, String> {
+    solve_part_one(*arrival, bus_ids)
+}
+
+pub fn part_two((_arrival, bus_ids): &Input<'_>) -> Result<BigUint, String> {
+    solve_part_two(bus_ids)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 13;
+    const DATA_FILE: &'static str = "13_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The waiting-product of the next bus is {}.",
+        "The first bus row is at timestamp {}.",
+    ];
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    type Input<'a> = Input<'a>;
+    type PartOne = Result<u32, String>;
+    type PartTwo = Result<BigUint, String>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<u32, String> {
+        self::part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> Result<BigUint, String> {
+        self::part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "939\n7,13,x,x,59,x,31,19";
+
+    #[test]
+    fn part1_examples() {
+        let (arrival, bus_ids) = parse_plan(EXAMPLE).unwrap();
+        assert_eq!(get_next_bus(arrival, &[7, 13, 59, 31, 19]), Some((59, 5)));
+        assert_eq!(solve_part_one(arrival, &bus_ids), Ok(295));
+    }
+
+    #[test]
+    fn waits_for_the_next_departure() {
+        assert_eq!(get_next_bus(14, &[7]), Some((7, 7)));
+        assert_eq!(get_next_bus(944, &[7, 13, 59]), Some((7, 1)));
+        assert_eq!(get_next_bus(944, &[]), None);
+        assert!(solve_part_one(944, &[]).is_err());
+    }
+
+    #[test]
+    fn part2_examples() {
+        let bus_row = |ids: &str| {
+            let bus_row = get_bus_row(&parse_plan(&format!("0\n{}", ids)).unwrap().1);
+            bus_row.unwrap().to_u128().unwrap()
+        };
+        assert_eq!(bus_row("7,13,x,x,59,x,31,19"), 1068781);
+        assert_eq!(bus_row("17,x,13,19"), 3417);
+        assert_eq!(bus_row("67,7,59,61"), 754018);
+        assert_eq!(bus_row("67,x,7,59,61"), 779210);
+        assert_eq!(bus_row("67,7,x,59,61"), 1261476);
+        assert_eq!(bus_row("1789,37,47,1889"), 1202161486);
+    }
+
+    #[test]
+    fn solves_arbitrary_schedules() {
+        let bus_row = |ids: &str| {
+            let bus_row = get_bus_row(&parse_plan(&format!("0\n{}", ids)).unwrap().1);
+            bus_row.map(|timestamp| timestamp.to_string())
+        };
+        assert_eq!(bus_row("4,x,6"), Ok("4".to_string()));
+        assert_eq!(bus_row("10,x,x,x,x,15"), Ok("10".to_string()));
+        assert_eq!(
+            get_bus_row(&[(0, 2), (1, 4)]),
+            Err(CongruenceError::Inconsistent)
+        );
+        // Neither the product of these IDs nor the timestamp fit into an `i64`.
+        assert_eq!(
+            bus_row("4294967291,x,4294967279,4294967231"),
+            Ok("2833507143679542015147522079".to_string())
+        );
+        // Nor into an `i128`, so the congruences are merged exactly.
+        assert_eq!(
+            bus_row("4294967291,x,4294967279,4294967231,x,x,4294967197,4294967189"),
+            Ok("897086269914697232616011873017608215069298255737".to_string())
+        );
+    }
+
+    #[test]
+    fn merges_exactly_like_native_integers() {
+        for ids in ["7,13,x,x,59,x,31,19", "4,x,6", "10,x,x,x,x,15", "1,x,3"] {
+            let (_arrival, bus_ids) = parse_plan(&format!("0\n{}", ids)).unwrap();
+            assert_eq!(
+                get_exact_bus_row(&bus_ids),
+                get_bus_row(&bus_ids),
+                "{}",
+                ids
+            );
+        }
+        assert_eq!(
+            get_exact_bus_row(&[(0, 2), (1, 4)]),
+            Err(CongruenceError::Inconsistent)
+        );
+    }
+
+    #[test]
+    fn fails_without_bus_row() {
+        for ids in ["2,4", "6,x,x,x,x,x,x,10"] {
+            let (_arrival, bus_ids) = parse_plan(&format!("0\n{}", ids)).unwrap();
+            assert_eq!(
+                solve_part_two(&bus_ids),
+                Err("The buses never depart in a row!".to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_bus_ids() {
+        assert!(parse_plan("939\nx,x").is_err());
+        assert!(parse_plan("939\n7,0").is_err());
+    }
+
+    #[test]
+    fn solves_generated_schedules() {
+        for (seed, size) in [(0, 1), (1, 5), (2, 50), (3, 200)] {
+            let (arrival, bus_ids) = parse(&generate(&mut Rng::new(seed), size)).unwrap();
+            assert_eq!(bus_ids[0].0, 0);
+            let ids = bus_ids.iter().map(|(_index, id)| *id).collect::<Vec<_>>();
+            let (bus_id, waiting_time) = get_next_bus(arrival, &ids).unwrap();
+            assert_eq!((arrival + waiting_time) % bus_id, 0);
+            assert!(ids.iter().all(|id| id - arrival % id >= waiting_time));
+            let timestamp = solve_part_two(&bus_ids).unwrap().to_u128().unwrap();
+            for (index, id) in bus_ids {
+                let departure = ModInt::from_signed(timestamp as i128, id.into())
+                    + ModInt::new(index as u64, id.into());
+                assert_eq!(departure.value(), 0);
+            }
+        }
+    }
+}
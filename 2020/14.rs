@@ -0,0 +1,261 @@
+use crate::utils::parse::{lines, parse_value, split_once, ParseError};
+use crate::utils::solution::Solution;
+use std::cell::OnceCell;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitMask {
+    pattern: u64,
+    value: u64,
+    /// Only built when decoding, as masks with many floating bits have lots of them.
+    memory_mutations: OnceCell<Vec<u64>>,
+}
+
+impl BitMask {
+    fn new(pattern: u64, value: u64) -> Self {
+        BitMask {
+            pattern,
+            value,
+            memory_mutations: OnceCell::new(),
+        }
+    }
+
+    fn mask(&self, value: u64) -> u64 {
+        (value & self.pattern) | self.value
+    }
+
+    fn decode_memory_address(&self, virtual_address: u64) -> Vec<u64> {
+        let fixed_address = (virtual_address & !self.pattern) | self.value;
+        self.memory_mutations
+            .get_or_init(|| {
+                mutate_with_or(
+                    (0..36)
+                        .map(|exponent| 2_u64.pow(exponent))
+                        .filter(|position| (position & self.pattern) != 0)
+                        .collect(),
+                )
+            })
+            .iter()
+            .map(|mutation| mutation | fixed_address)
+            .collect()
+    }
+}
+
+impl FromStr for BitMask {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some((index, character)) = s
+            .char_indices()
+            .find(|(_index, character)| !matches!(character, '0' | '1' | 'X'))
+        {
+            return Err(ParseError::new(
+                &s[index..index + character.len_utf8()],
+                "Unknown mask bit!",
+            ));
+        }
+        if s.len() != 36 {
+            return Err(ParseError::new(s, "A mask consists of 36 bits!"));
+        }
+        let to_number = |binary: &str| {
+            u64::from_str_radix(binary, 2).map_err(|err| ParseError::new(s, err.to_string()))
+        };
+        Ok(BitMask::new(
+            to_number(&s.replace('1', "0").replace('X', "1"))?,
+            to_number(&s.replace('X', "0"))?,
+        ))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Transaction {
+    BitMask(BitMask),
+    MemSet(u64, u64),
+}
+
+impl FromStr for Transaction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(mask) = s.strip_prefix("mask = ") {
+            return Ok(Transaction::BitMask(mask.parse::<BitMask>()?));
+        }
+        if let Some(assignment) = s.strip_prefix("mem[") {
+            let (address, value) = split_once(assignment, "] = ")?;
+            return Ok(Transaction::MemSet(
+                parse_value(address)?,
+                parse_value(value)?,
+            ));
+        }
+        Err(ParseError::new(s, "Unknown transaction!"))
+    }
+}
+
+fn parse_mask_mem(input: &str) -> Result<Vec<Transaction>, ParseError> {
+    lines(input).map(str::parse::<Transaction>).collect()
+}
+
+/// Combines every subset of `linear` with or, where the n-th result holds the
+/// values whose bits are set in n.
+fn mutate_with_or(linear: Vec<u64>) -> Vec<u64> {
+    let mut results = Vec::with_capacity(1 << linear.len());
+    results.push(0);
+    for current in linear {
+        mutate_with_or_internal(current, &mut results);
+    }
+    results
+}
+
+/// Doubles the results by adding a copy of each, which is combined with `current`.
+fn mutate_with_or_internal(current: u64, results: &mut Vec<u64>) {
+    results.extend_from_within(..);
+    let half = results.len() / 2;
+    results[half..]
+        .iter_mut()
+        .for_each(|result| *result |= current);
+}
+
+fn memory_sum(transactions: &[Transaction]) -> u64 {
+    let mut mask = BitMask::new(0, 0);
+    let mut memory: BTreeMap<u64, u64> = BTreeMap::new();
+    transactions
+        .iter()
+        .for_each(|transaction| match transaction {
+            Transaction::BitMask(new_mask) => mask = new_mask.clone(),
+            Transaction::MemSet(address, value) => {
+                let masked_value = mask.mask(*value);
+                memory
+                    .entry(*address)
+                    .and_modify(|val| *val = masked_value)
+                    .or_insert(masked_value);
+            }
+        });
+    memory.values().sum()
+}
+
+fn solve_part_one(transactions: &[Transaction]) -> u64 {
+    memory_sum(transactions)
+}
+
+fn memory_sum_with_mad(transactions: &[Transaction]) -> u64 {
+    let mut mask = BitMask::new(0, 0);
+    let mut memory: BTreeMap<u64, u64> = BTreeMap::new();
+    transactions
+        .iter()
+        .for_each(|transaction| match transaction {
+            Transaction::BitMask(new_mask) => mask = new_mask.clone(),
+            Transaction::MemSet(address, value) => {
+                mask.decode_memory_address(*address)
+                    .iter()
+                    .for_each(|physical_address| {
+                        memory
+                            .entry(*physical_address)
+                            .and_modify(|val| *val = *value)
+                            .or_insert(*value);
+                    });
+            }
+        });
+    memory.values().sum()
+}
+
+fn solve_part_two(transactions: &[Transaction]) -> u64 {
+    memory_sum_with_mad(transactions)
+}
+
+pub type Input<'a> = Vec<Transaction>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_mask_mem(input)
+}
+
+pub fn part_one(transactions: &Input<'_>) -> u64 {
+    solve_part_one(transactions)
+}
+
+pub fn part_two(transactions: &Input<'_>) -> u64 {
+    solve_part_two(transactions)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 14;
+    const DATA_FILE: &'static str = "14_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The memory residue summed up is {}.",
+        "The memory residue in memory address decoder mode summed up is {}.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(transactions: &Self::Input<'_>) -> u64 {
+        self::part_one(transactions)
+    }
+
+    fn part_two(transactions: &Self::Input<'_>) -> u64 {
+        self::part_two(transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X
+mem[8] = 11
+mem[7] = 101
+mem[8] = 0";
+
+    #[test]
+    fn part1_examples() {
+        let transactions = parse_mask_mem(EXAMPLE).unwrap();
+        let mask = match &transactions[0] {
+            Transaction::BitMask(mask) => mask,
+            transaction => panic!("Unexpected {:?}!", transaction),
+        };
+        assert_eq!([11, 101, 0].map(|value| mask.mask(value)), [73, 101, 64]);
+        assert_eq!(solve_part_one(&transactions), 165);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let transactions = parse_mask_mem(
+            "\
+mask = 000000000000000000000000000000X1001X
+mem[42] = 100
+mask = 00000000000000000000000000000000X0XX
+mem[26] = 1",
+        )
+        .unwrap();
+        let mask = "000000000000000000000000000000X1001X"
+            .parse::<BitMask>()
+            .unwrap();
+        let mut addresses = mask.decode_memory_address(42);
+        addresses.sort_unstable();
+        assert_eq!(addresses, vec![26, 27, 58, 59]);
+        assert_eq!(solve_part_two(&transactions), 208);
+    }
+
+    #[test]
+    fn mutates_all_combinations() {
+        assert_eq!(mutate_with_or(vec![1, 4]), vec![0, 1, 4, 5]);
+        assert_eq!(mutate_with_or(Vec::new()), vec![0]);
+    }
+
+    #[test]
+    fn rejects_invalid_masks() {
+        assert!("XX1".parse::<BitMask>().is_err());
+        assert!("mask = 00000000000000000000000000000000X0X2"
+            .parse::<Transaction>()
+            .is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use crate::utils::parse::{lines, parse_value, ParseError};
+use crate::utils::solution::Solution;
+use std::collections::HashMap;
+
+fn parse_num_list(input: &str) -> Result<Vec<u32>, ParseError> {
+    let starting_values = lines(input)
+        .flat_map(|line| line.split(','))
+        .map(parse_value)
+        .collect::<Result<Vec<_>, _>>()?;
+    if starting_values.is_empty() {
+        return Err(ParseError::new(input, "No starting values!"));
+    }
+    Ok(starting_values)
+}
+
+struct SplittedMap<V, const STATIC_SIZE: usize> {
+    /// Too large for the stack, so it's allocated once with a fixed length.
+    hot_table: Box<[Option<V>]>,
+    pub cold_map: HashMap<usize, V>,
+}
+
+impl<V, const STATIC_SIZE: usize> SplittedMap<V, STATIC_SIZE>
+where
+    V: Copy,
+    Option<V>: Copy,
+{
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            hot_table: vec![None; STATIC_SIZE].into_boxed_slice(),
+            cold_map: HashMap::with_capacity(capacity),
+        }
+    }
+
+    fn get(&self, key: &usize) -> Option<V> {
+        if *key < STATIC_SIZE {
+            unsafe { *self.hot_table.get_unchecked(*key) }
+        } else {
+            self.cold_map.get(key).copied()
+        }
+    }
+
+    fn set(&mut self, key: usize, value: V) {
+        if key < STATIC_SIZE {
+            unsafe {
+                *self.hot_table.get_unchecked_mut(key) = Some(value);
+            }
+        } else {
+            self.cold_map.insert(key, value);
+        }
+    }
+}
+
+fn get_nth_number(starting_values: &[u32], nth: usize) -> u32 {
+    let mut last_values: SplittedMap<u32, { 1024 * 2048 }> =
+        SplittedMap::with_capacity(2039983 * (nth > { 1024 * 2048 }) as usize);
+    starting_values
+        .iter()
+        .take(starting_values.len() - 1)
+        .enumerate()
+        .for_each(|(index, value)| last_values.set(*value as usize, index as u32 + 1));
+
+    let mut last_value = *starting_values.last().expect("No starting value!") as usize;
+
+    (starting_values.len()..nth).for_each(|index| {
+        let current_value = last_values
+            .get(&last_value)
+            .map(|last_occuring_index| index as u32 - last_occuring_index)
+            .unwrap_or(0);
+
+        last_values.set(last_value, index as u32);
+        last_value = current_value as usize;
+    });
+    last_value as u32
+}
+
+const PART_ONE_TURN: usize = 2020;
+const PART_TWO_TURN: usize = 30000000;
+
+fn solve_part_one(starting_values: &[u32]) -> u32 {
+    get_nth_number(starting_values, PART_ONE_TURN)
+}
+
+fn solve_part_two(starting_values: &[u32]) -> u32 {
+    get_nth_number(starting_values, PART_TWO_TURN)
+}
+
+pub type Input<'a> = Vec<u32>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_num_list(input)
+}
+
+pub fn part_one(starting_values: &Input<'_>) -> u32 {
+    solve_part_one(starting_values)
+}
+
+pub fn part_two(starting_values: &Input<'_>) -> u32 {
+    solve_part_two(starting_values)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 15;
+    const DATA_FILE: &'static str = "15_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "{} will be the 2020th number spoken.",
+        "{} will be the 30000000th number spoken.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(starting_values: &Self::Input<'_>) -> u32 {
+        self::part_one(starting_values)
+    }
+
+    fn part_two(starting_values: &Self::Input<'_>) -> u32 {
+        self::part_two(starting_values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(get_nth_number(&parse_num_list("0,3,6").unwrap(), 2020), 436);
+        assert_eq!(get_nth_number(&parse_num_list("1,3,2").unwrap(), 2020), 1);
+        assert_eq!(get_nth_number(&parse_num_list("2,1,3").unwrap(), 2020), 10);
+        assert_eq!(get_nth_number(&parse_num_list("1,2,3").unwrap(), 2020), 27);
+        assert_eq!(get_nth_number(&parse_num_list("2,3,1").unwrap(), 2020), 78);
+        assert_eq!(get_nth_number(&parse_num_list("3,2,1").unwrap(), 2020), 438);
+        assert_eq!(
+            get_nth_number(&parse_num_list("3,1,2").unwrap(), 2020),
+            1836
+        );
+    }
+
+    #[test]
+    fn part2_examples() {
+        assert_eq!(solve_part_two(&parse_num_list("0,3,6").unwrap()), 175594);
+        assert_eq!(solve_part_two(&parse_num_list("3,1,2").unwrap()), 362);
+    }
+
+    #[test]
+    fn rejects_empty_list() {
+        assert!(parse_num_list("").is_err());
+        assert!(parse_num_list("\n").is_err());
+    }
+}
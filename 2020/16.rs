@@ -0,0 +1,258 @@
+use crate::utils::bigint::Number;
+use crate::utils::parse::{blocks, lines, parse_value, split_once, ParseError};
+use crate::utils::solution::Solution;
+use std::collections::HashMap;
+
+type Rules = HashMap<String, Vec<(i32, i32)>>;
+type Tickets = Vec<Vec<i32>>;
+type TicketsSlice = [Vec<i32>];
+
+fn parse_ticket_notes(input: &str) -> Result<(Rules, Tickets), ParseError> {
+    let mut sections = blocks(input);
+    let rule_notes = sections.next().unwrap_or_default();
+
+    let rules = lines(rule_notes)
+        .map(|line| {
+            let (rule_name, rule_body) = split_once(line, ": ")?;
+            let rule_body = rule_body
+                .split(" or ")
+                .map(|rule_range| {
+                    let (min, max) = split_once(rule_range, "-")?;
+                    Ok((parse_value(min)?, parse_value(max)?))
+                })
+                .collect::<Result<_, ParseError>>()?;
+            Ok((rule_name.to_string(), rule_body))
+        })
+        .collect::<Result<Rules, ParseError>>()?;
+
+    let tickets = sections
+        .flat_map(lines)
+        .filter(|line| !matches!(*line, "your ticket:" | "nearby tickets:"))
+        .map(|ticket| {
+            let values = ticket
+                .split(',')
+                .map(parse_value)
+                .collect::<Result<Vec<_>, _>>()?;
+            if values.len() != rules.len() {
+                return Err(ParseError::new(ticket, "A ticket needs a value per rule!"));
+            }
+            Ok(values)
+        })
+        .collect::<Result<Tickets, _>>()?;
+    if tickets.is_empty() {
+        return Err(ParseError::new(
+            &input[input.len()..],
+            "Missing your ticket!",
+        ));
+    }
+
+    Ok((rules, tickets))
+}
+
+fn value_in_ranges(value: &i32, ranges: &[&(i32, i32)]) -> bool {
+    ranges.iter().any(|(min, max)| (min..=max).contains(&value))
+}
+
+fn scanning_error_rate(rules: &Rules, tickets: &TicketsSlice) -> i32 {
+    let ranges: Vec<&(i32, i32)> = rules.values().flatten().collect();
+    tickets
+        .iter()
+        .map(|ticket| {
+            ticket
+                .iter()
+                .filter(|value| !value_in_ranges(value, &ranges))
+                .sum::<i32>()
+        })
+        .sum()
+}
+
+fn solve_part_one(rules: &Rules, tickets: &TicketsSlice) -> i32 {
+    scanning_error_rate(rules, tickets)
+}
+
+fn get_labels(rules: &Rules, tickets: &TicketsSlice) -> Vec<String> {
+    let ranges: Vec<&(i32, i32)> = rules.values().flatten().collect();
+    let valid_tickets: Vec<&Vec<i32>> = tickets
+        .iter()
+        .skip(1)
+        .filter(|ticket| ticket.iter().all(|value| value_in_ranges(value, &ranges)))
+        .collect();
+
+    let length_attributes = valid_tickets[0].len();
+    let mut possible_attribute_names = vec![Vec::new(); rules.len()];
+
+    rules.iter().for_each(|(attribute_name, attribute_ranges)| {
+        (0_usize..length_attributes)
+            .filter(|&attribute_index| {
+                valid_tickets.iter().all(|ticket| {
+                    value_in_ranges(
+                        &(**ticket)[attribute_index],
+                        &attribute_ranges.iter().collect::<Vec<_>>(),
+                    )
+                })
+            })
+            .for_each(|attribute_index| {
+                possible_attribute_names[attribute_index].push(attribute_name)
+            });
+    });
+
+    // determine
+    let mut attribute_names = vec![String::new(); rules.len()];
+    while attribute_names
+        .iter()
+        .any(|attribute_name| attribute_name.is_empty())
+    {
+        possible_attribute_names
+            .iter_mut()
+            .enumerate()
+            .filter(|(_attribute_index, possible_names)| possible_names.len() == 1)
+            .for_each(|(attribute_index, possible_names)| {
+                attribute_names[attribute_index] = (*possible_names).pop().unwrap().to_string();
+            });
+        possible_attribute_names
+            .iter_mut()
+            .for_each(|possible_names| {
+                let removing_indexes: Vec<usize> = possible_names
+                    .iter()
+                    .enumerate()
+                    .filter(|(_possible_index, possible_name)| {
+                        attribute_names.contains(possible_name)
+                    })
+                    .map(|(possible_index, _possible_name)| possible_index)
+                    .collect();
+                removing_indexes.iter().for_each(|possible_index| {
+                    possible_names.remove(*possible_index);
+                });
+            });
+    }
+    attribute_names
+}
+
+fn solve_part_two<N: Number + From<i32>>(rules: &Rules, tickets: &TicketsSlice) -> N {
+    let labels = get_labels(rules, tickets);
+    labels
+        .iter()
+        .enumerate()
+        .filter(|(_attribute_index, attribute_name)| attribute_name.starts_with("departure"))
+        .map(|(attribute_index, _attribute_name)| N::from(tickets[0][attribute_index]))
+        .product()
+}
+
+pub type Input<'a> = (Rules, Tickets);
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_ticket_notes(input)
+}
+
+pub fn part_one((rules, tickets): &Input<'_>) -> i32 {
+    solve_part_one(rules, tickets)
+}
+
+/// Multiplies in an `i64` for the puzzle, or exactly as a `BigInt`.
+pub fn part_two<N: Number + From<i32>>((rules, tickets): &Input<'_>) -> N {
+    solve_part_two(rules, tickets)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 16;
+    const DATA_FILE: &'static str = "16_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The scanning error rate is {}.",
+        "The departure product is {}.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = i32;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> i32 {
+        self::part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> i64 {
+        self::part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::bigint::BigInt;
+
+    const EXAMPLE: &str = "\
+class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12";
+
+    #[test]
+    fn part1_examples() {
+        let (rules, tickets) = parse_ticket_notes(EXAMPLE).unwrap();
+        assert_eq!(tickets.len(), 5);
+        assert_eq!(solve_part_one(&rules, &tickets), 71);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let (rules, tickets) = parse_ticket_notes(
+            "\
+class: 0-1 or 4-19
+departure row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9",
+        )
+        .unwrap();
+        assert_eq!(
+            get_labels(&rules, &tickets),
+            vec!["departure row", "class", "seat"]
+        );
+        assert_eq!(solve_part_two::<i64>(&rules, &tickets), 11);
+    }
+
+    #[test]
+    fn multiplies_departures_exactly() {
+        // The nearby ticket's value `field` only fits the rules from `field` on.
+        let rules = (0..6)
+            .map(|field| format!("departure {}: 0-{} or 1000000-2000000", field, field))
+            .collect::<Vec<_>>();
+        let notes = format!(
+            "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n0,1,2,3,4,5",
+            rules.join("\n"),
+            ["1999999"; 6].join(",")
+        );
+        let product = part_two::<BigInt>(&parse_ticket_notes(&notes).unwrap());
+        assert_eq!(product, BigInt::from(1999999_u32).pow(6));
+        assert_eq!(
+            product.to_string(),
+            "63999808000239999840000059999988000001"
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_tickets() {
+        assert!(parse_ticket_notes("class: 1-3 or 5-7\n\nyour ticket:\n7,1").is_err());
+        assert!(parse_ticket_notes("class: 1-3 or 5-7\n\nyour ticket:\n").is_err());
+    }
+}
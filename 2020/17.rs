@@ -0,0 +1,150 @@
+use crate::utils::automaton::{Automaton, Rule};
+use crate::utils::grid::Grid;
+use crate::utils::parse::ParseError;
+use crate::utils::solution::Solution;
+use std::collections::BTreeSet;
+
+#[derive(Ord, PartialOrd, PartialEq, Eq, Hash, Clone, Debug)]
+pub struct Position {
+    x: i64,
+    y: i64,
+    z: i64,
+    w: i64,
+}
+
+impl Position {
+    fn new(x: i64, y: i64, z: i64, w: i64) -> Self {
+        Position { x, y, z, w }
+    }
+
+    fn neighborhood(&self, is_4_dimensional: bool) -> impl Iterator<Item = Self> {
+        let center = self.clone();
+        let w_offsets = -(is_4_dimensional as i64)..=is_4_dimensional as i64;
+        (-1..=1)
+            .flat_map(|x_offset| (-1..=1).map(move |y_offset| (x_offset, y_offset)))
+            .flat_map(|(x_offset, y_offset)| {
+                (-1..=1).map(move |z_offset| (x_offset, y_offset, z_offset))
+            })
+            .flat_map(move |(x_offset, y_offset, z_offset)| {
+                w_offsets
+                    .clone()
+                    .map(move |w_offset| (x_offset, y_offset, z_offset, w_offset))
+            })
+            .filter(|offset| *offset != (0, 0, 0, 0))
+            .map(move |(x_offset, y_offset, z_offset, w_offset)| {
+                Position::new(
+                    center.x + x_offset,
+                    center.y + y_offset,
+                    center.z + z_offset,
+                    center.w + w_offset,
+                )
+            })
+    }
+}
+
+fn parse_map(input: &str) -> Result<BTreeSet<Position>, ParseError> {
+    let map = Grid::parse(input, |character| match character {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+    Ok(map
+        .iter()
+        .filter(|(_position, is_active)| **is_active)
+        .map(|((x, y), _is_active)| Position::new(x as i64, (map.height() - 1 - y) as i64, 0, 0))
+        .collect())
+}
+
+fn count_after_cycles(
+    active_cubes: &BTreeSet<Position>,
+    nth: usize,
+    is_4_dimensional: bool,
+) -> usize {
+    let mut automaton = Automaton::new(
+        active_cubes.iter().cloned(),
+        |cube: &Position| cube.neighborhood(is_4_dimensional),
+        Rule::new(&[3], &[2, 3]),
+    );
+    automaton.run(nth);
+    automaton.population()
+}
+
+fn solve_part_one(active_cubes: &BTreeSet<Position>) -> usize {
+    count_after_cycles(active_cubes, 6, false)
+}
+
+fn solve_part_two(active_cubes: &BTreeSet<Position>) -> usize {
+    count_after_cycles(active_cubes, 6, true)
+}
+
+pub type Input<'a> = BTreeSet<Position>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_map(input)
+}
+
+pub fn part_one(active_cubes: &Input<'_>) -> usize {
+    solve_part_one(active_cubes)
+}
+
+pub fn part_two(active_cubes: &Input<'_>) -> usize {
+    solve_part_two(active_cubes)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 17;
+    const DATA_FILE: &'static str = "17_data.map";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "There are {} cubes left in the active state after the sixth cycle.",
+        "There are {} cubes left in the active state after the sixth cycle in 4 dimensions.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(active_cubes: &Self::Input<'_>) -> usize {
+        self::part_one(active_cubes)
+    }
+
+    fn part_two(active_cubes: &Self::Input<'_>) -> usize {
+        self::part_two(active_cubes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = ".#.\n..#\n###";
+
+    #[test]
+    fn part1_examples() {
+        let active_cubes = parse_map(EXAMPLE).unwrap();
+        assert_eq!(active_cubes.len(), 5);
+        assert!(active_cubes.contains(&Position::new(1, 2, 0, 0)));
+        assert_eq!(count_after_cycles(&active_cubes, 1, false), 11);
+        assert_eq!(solve_part_one(&active_cubes), 112);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let active_cubes = parse_map(EXAMPLE).unwrap();
+        assert_eq!(count_after_cycles(&active_cubes, 1, true), 29);
+        assert_eq!(solve_part_two(&active_cubes), 848);
+    }
+
+    #[test]
+    fn counts_neighborhoods() {
+        let origin = Position::new(0, 0, 0, 0);
+        assert_eq!(origin.neighborhood(false).count(), 26);
+        assert_eq!(origin.neighborhood(true).count(), 80);
+    }
+}
@@ -0,0 +1,329 @@
+use crate::utils::bigint::Number;
+use crate::utils::parse::{lines, parse_value, ParseError};
+use crate::utils::solution::Solution;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display};
+use std::str::FromStr;
+
+/// What expressions are calculated in: an `i64` for the puzzle, or a `BigInt`
+/// for exact results.
+pub trait Value: Number + FromStr<Err: Display> + Debug + 'static {}
+
+impl<N> Value for N where N: Number + FromStr<Err: Display> + Debug + 'static {}
+
+pub trait Eval<N>: Debug {
+    fn calculate_latin_order(&self) -> N;
+    fn calculate_reversed_order(&self) -> N;
+}
+
+#[derive(Debug)]
+struct Num<N> {
+    internal: N,
+}
+
+impl<N: Value> Eval<N> for Num<N> {
+    fn calculate_latin_order(&self) -> N {
+        self.internal.clone()
+    }
+
+    fn calculate_reversed_order(&self) -> N {
+        self.internal.clone()
+    }
+}
+
+impl<N: Value> FromStr for Num<N> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Num {
+            internal: parse_value(s.trim())?,
+        })
+    }
+}
+
+#[derive(Debug)]
+enum Operation {
+    NoOp,
+    Add,
+    Multiply,
+}
+
+impl FromStr for Operation {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" => Ok(Operation::NoOp),
+            "+" => Ok(Operation::Add),
+            "*" => Ok(Operation::Multiply),
+            _ => Err(ParseError::new(s, "Unknown operation!")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Expression<N = i64> {
+    sub_evaluations: Vec<(Operation, Box<dyn Eval<N>>)>,
+}
+
+impl<N: Value> Eval<N> for Expression<N> {
+    fn calculate_latin_order(&self) -> N {
+        let (_no_op, first_eval) = &self.sub_evaluations[0];
+        if self.sub_evaluations.len() == 1 {
+            return (*first_eval).calculate_latin_order();
+        }
+        self.sub_evaluations.iter().skip(1).fold(
+            (*first_eval).calculate_latin_order(),
+            |acc, (operation, sub_evaluation)| match operation {
+                Operation::Add => acc + (*sub_evaluation).calculate_latin_order(),
+                Operation::Multiply => acc * (*sub_evaluation).calculate_latin_order(),
+                Operation::NoOp => panic!("Invalid NoOp!"),
+            },
+        )
+    }
+
+    fn calculate_reversed_order(&self) -> N {
+        let mut product = N::from(1);
+        let mut current_index = 0;
+        while let Some((_sign, sub_evaluation)) = self.sub_evaluations.get(current_index) {
+            let mut additive_part_value = sub_evaluation.calculate_reversed_order();
+            current_index += 1;
+            while let Some((Operation::Add, summand)) = self.sub_evaluations.get(current_index) {
+                additive_part_value = additive_part_value + summand.calculate_reversed_order();
+                current_index += 1;
+            }
+            product = product * additive_part_value;
+        }
+
+        product
+    }
+}
+
+fn take_inner<'a>(
+    opening_bracket: &'a str,
+    elements: &mut VecDeque<&'a str>,
+) -> Result<VecDeque<&'a str>, ParseError> {
+    let mut bracket_stack = 1_u32;
+    let mut inner = VecDeque::new();
+    loop {
+        let element = elements.pop_front().ok_or_else(|| {
+            ParseError::new(
+                opening_bracket,
+                "Expression ended before matching closing bracket found!",
+            )
+        })?;
+        match element {
+            "(" => {
+                inner.push_back(element);
+                bracket_stack += 1;
+            }
+            ")" => {
+                bracket_stack -= 1;
+                if bracket_stack == 0 {
+                    break;
+                } else {
+                    inner.push_back(element);
+                }
+            }
+            _ => inner.push_back(element),
+        }
+    }
+    Ok(inner)
+}
+
+fn parse_operand<'a, N: Value>(
+    element: &'a str,
+    elements: &mut VecDeque<&'a str>,
+) -> Result<Box<dyn Eval<N>>, ParseError> {
+    match element.chars().next() {
+        Some('0'..='9') => Ok(Box::new(element.parse::<Num<N>>()?)),
+        Some('(') => Ok(Box::new(parse_expression(
+            element,
+            take_inner(element, elements)?,
+        )?)),
+        _ => Err(ParseError::new(element, "Unidentified character!")),
+    }
+}
+
+/// The `context` is reported, if there are no elements.
+fn parse_expression<'a, N: Value>(
+    context: &'a str,
+    mut elements: VecDeque<&'a str>,
+) -> Result<Expression<N>, ParseError> {
+    let first_element = elements
+        .pop_front()
+        .ok_or_else(|| ParseError::new(context, "Empty expression!"))?;
+    let mut sub_evaluations = vec![(
+        Operation::NoOp,
+        parse_operand(first_element, &mut elements)?,
+    )];
+
+    while let Some(sign) = elements.pop_front() {
+        let operation = match sign.parse()? {
+            Operation::NoOp => return Err(ParseError::new(sign, "Missing operation!")),
+            operation => operation,
+        };
+        let element = elements
+            .pop_front()
+            .ok_or_else(|| ParseError::new(sign, "No expression after sign!"))?;
+        sub_evaluations.push((operation, parse_operand(element, &mut elements)?));
+    }
+
+    Ok(Expression { sub_evaluations })
+}
+
+/// Splits a line into numbers, signs and brackets.
+fn tokenize(line: &str) -> VecDeque<&str> {
+    let mut tokens = VecDeque::new();
+    let mut characters = line.char_indices().peekable();
+    while let Some((start, character)) = characters.next() {
+        let mut end = start + character.len_utf8();
+        if character.is_ascii_digit() {
+            while let Some((index, _digit)) =
+                characters.next_if(|(_index, next)| next.is_ascii_digit())
+            {
+                end = index + 1;
+            }
+        }
+        if !character.is_whitespace() {
+            tokens.push_back(&line[start..end]);
+        }
+    }
+    tokens
+}
+
+fn parse_expressions<N: Value>(input: &str) -> Result<Vec<Expression<N>>, ParseError> {
+    lines(input)
+        .map(|line| parse_expression(line, tokenize(line)))
+        .collect()
+}
+
+fn solve_part_one<N: Value>(expressions: &[Expression<N>]) -> N {
+    expressions
+        .iter()
+        .map(|expression| expression.calculate_latin_order())
+        .sum()
+}
+
+fn solve_part_two<N: Value>(expressions: &[Expression<N>]) -> N {
+    expressions
+        .iter()
+        .map(|expression| expression.calculate_reversed_order())
+        .sum()
+}
+
+pub type Input<'a> = Vec<Expression>;
+
+/// Parses the numbers as `i64`s for the puzzle, or as `BigInt`s for exact results.
+pub fn parse<N: Value>(input: &str) -> Result<Vec<Expression<N>>, ParseError> {
+    parse_expressions(input)
+}
+
+pub fn part_one<N: Value>(expressions: &[Expression<N>]) -> N {
+    solve_part_one(expressions)
+}
+
+pub fn part_two<N: Value>(expressions: &[Expression<N>]) -> N {
+    solve_part_two(expressions)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 18;
+    const DATA_FILE: &'static str = "18_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The sum of the resulting values is {} calculated in latin order.",
+        "The sum of the resulting values is {} calculated in reversed order.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(expressions: &Self::Input<'_>) -> i64 {
+        self::part_one(expressions)
+    }
+
+    fn part_two(expressions: &Self::Input<'_>) -> i64 {
+        self::part_two(expressions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::bigint::BigInt;
+
+    const EXAMPLES: [(&str, i64, i64); 6] = [
+        ("1 + 2 * 3 + 4 * 5 + 6", 71, 231),
+        ("1 + (2 * 3) + (4 * (5 + 6))", 51, 51),
+        ("2 * 3 + (4 * 5)", 26, 46),
+        ("5 + (8 * 3 + 9 + 3 * 4 * 3)", 437, 1445),
+        ("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", 12240, 669060),
+        (
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+            13632,
+            23340,
+        ),
+    ];
+
+    #[test]
+    fn part1_examples() {
+        for (expression, latin_order, _reversed_order) in EXAMPLES {
+            let expressions = parse_expressions::<i64>(expression).unwrap();
+            assert_eq!(solve_part_one(&expressions), latin_order, "{}", expression);
+        }
+    }
+
+    #[test]
+    fn part2_examples() {
+        for (expression, _latin_order, reversed_order) in EXAMPLES {
+            let expressions = parse_expressions::<i64>(expression).unwrap();
+            assert_eq!(
+                solve_part_two(&expressions),
+                reversed_order,
+                "{}",
+                expression
+            );
+        }
+    }
+
+    #[test]
+    fn calculates_exactly() {
+        let expression = "(99999999999 * 99999999999) + 1 * 99999999999";
+        let expressions = parse::<BigInt>(expression).unwrap();
+        assert_eq!(
+            part_one(&expressions).to_string(),
+            "999999999970000000000399999999998"
+        );
+        assert_eq!(
+            part_two(&expressions).to_string(),
+            "999999999970000000000399999999998"
+        );
+        let expressions = parse::<BigInt>("99999999999 * 99999999999 + 1").unwrap();
+        assert_eq!(part_two(&expressions).to_string(), "9999999999900000000000");
+        assert!(parse::<i64>("99999999999999999999").is_err());
+    }
+
+    #[test]
+    fn tokenizes_numbers_and_brackets() {
+        assert_eq!(
+            tokenize("12 * (3+45)"),
+            VecDeque::from(vec!["12", "*", "(", "3", "+", "45", ")"])
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        assert!(parse_expressions::<i64>("1 + (2 * 3").is_err());
+        assert!(parse_expressions::<i64>("1 +").is_err());
+        assert!(parse_expressions::<i64>("1 2").is_err());
+        assert!(parse_expressions::<i64>("1 - 2").is_err());
+    }
+}
@@ -0,0 +1,387 @@
+use crate::utils::parse::{blocks, lines, parse_value, split_once, ParseError};
+use crate::utils::solution::Solution;
+use crate::utils::unique::*;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Rule {
+    /// The outer is a or group, the inner a specific order of rules.
+    Meta(Vec<Vec<u32>>),
+    Data(char),
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(data) = s
+            .trim()
+            .strip_prefix('\"')
+            .and_then(|data| data.strip_suffix('\"'))
+        {
+            let mut characters = data.chars();
+            return match (characters.next(), characters.next()) {
+                (Some(character), None) => Ok(Rule::Data(character)),
+                _ => Err(ParseError::new(data, "Data has to be a single character!")),
+            };
+        }
+        Ok(Rule::Meta(
+            s.split('|')
+                .map(|or_group| or_group.split_whitespace().map(parse_value).collect())
+                .collect::<Result<_, ParseError>>()?,
+        ))
+    }
+}
+
+/// An or group being matched, kept on a work stack instead of the call stack.
+struct OrGroup<'a> {
+    alternatives: &'a [Vec<u32>],
+    /// The positions the group is matched from.
+    start: Vec<u32>,
+    alternative: usize,
+    /// The rule of the alternative to match next.
+    step: usize,
+    paths: Vec<u32>,
+    matched: Vec<u32>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rules {
+    rules: BTreeMap<u32, Rule>,
+}
+
+impl Rules {
+    pub fn check(&self, message: &str) -> bool {
+        let paths = self.check_internal(message, 0, vec![0]);
+
+        paths.contains(&(message.len() as u32))
+    }
+
+    /// Matches the rule from every position in `paths` and returns the positions
+    /// after the matches. Looping rules nest as deep as the message is long, so
+    /// the or groups are matched on a work stack.
+    fn check_internal(&self, message: &str, next_rule: u32, paths: Vec<u32>) -> Vec<u32> {
+        let mut groups = Vec::new();
+        let mut matched = self.enter_rule(message, next_rule, paths, &mut groups);
+        while let Some(group) = groups.last_mut() {
+            if let Some(next_paths) = matched.take() {
+                group.paths = next_paths;
+                group.step += 1;
+            }
+            match group.alternatives[group.alternative].get(group.step) {
+                Some(&rule) => {
+                    let paths = std::mem::take(&mut group.paths);
+                    matched = self.enter_rule(message, rule, paths, &mut groups);
+                }
+                None => {
+                    let paths = std::mem::take(&mut group.paths);
+                    group.matched.extend(paths);
+                    group.alternative += 1;
+                    group.step = 0;
+                    if group.alternative < group.alternatives.len() {
+                        group.paths = group.start.clone();
+                    } else {
+                        let group = groups.pop().expect("No or group!");
+                        matched = Some(group.matched.into_iter().unique().collect());
+                    }
+                }
+            }
+        }
+        matched.expect("Rule wasn't matched!")
+    }
+
+    /// Matches a data rule right away, while an or group is pushed to be matched
+    /// by `check_internal`.
+    fn enter_rule<'a>(
+        &'a self,
+        message: &str,
+        next_rule: u32,
+        paths: Vec<u32>,
+        groups: &mut Vec<OrGroup<'a>>,
+    ) -> Option<Vec<u32>> {
+        if paths.is_empty() {
+            return Some(paths);
+        }
+        match self.rules.get(&next_rule).expect("Rule not found!") {
+            Rule::Data(data) => Some(
+                paths
+                    .into_iter()
+                    .filter(|index| message.chars().nth(*index as usize) == Some(*data))
+                    .map(|path| path + 1)
+                    .collect(),
+            ),
+            Rule::Meta(alternatives) => {
+                groups.push(OrGroup {
+                    alternatives,
+                    start: paths.clone(),
+                    alternative: 0,
+                    step: 0,
+                    paths,
+                    matched: Vec::new(),
+                });
+                None
+            }
+        }
+    }
+
+    pub fn check_non_recursive(&self, message: &str) -> bool {
+        let mut starting_index = 0;
+
+        self.check_non_recursive_internal(message, 0, &mut starting_index)
+            && starting_index == message.len()
+    }
+
+    fn check_non_recursive_internal(
+        &self,
+        message: &str,
+        next_rule: u32,
+        next_index: &mut usize,
+    ) -> bool {
+        if *next_index > message.len() {
+            return false;
+        }
+        match self.rules.get(&next_rule).expect("Rule not found!") {
+            Rule::Data(data) => {
+                let is_valid = message.chars().nth(*next_index) == Some(*data);
+                *next_index += is_valid as usize;
+                is_valid
+            }
+            Rule::Meta(sub_rules) => sub_rules.iter().any(|rule_chain| {
+                let mut current_index = *next_index;
+                if rule_chain.iter().all(|rule| {
+                    self.check_non_recursive_internal(message, *rule, &mut current_index)
+                }) {
+                    *next_index = current_index;
+                    return true;
+                }
+                false
+            }),
+        }
+    }
+
+    fn patch_rules_part_2(&mut self) {
+        self.rules
+            .entry(8)
+            .and_modify(|rule_8| *rule_8 = Rule::Meta(vec![vec![42], vec![42, 8]]));
+        self.rules
+            .entry(11)
+            .and_modify(|rule_11| *rule_11 = Rule::Meta(vec![vec![42, 31], vec![42, 11, 31]]));
+    }
+}
+
+impl FromStr for Rules {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let rules = lines(s)
+            .map(|line| {
+                let (id, rule) = split_once(line, ": ")?;
+                Ok((parse_value::<u32>(id)?, rule.parse::<Rule>()?))
+            })
+            .collect::<Result<BTreeMap<_, _>, ParseError>>()?;
+
+        if let Some(unknown_id) = lines(s)
+            .filter_map(|line| line.split_once(": "))
+            .flat_map(|(_id, rule)| rule.split_whitespace())
+            .find(|id| id.parse().is_ok_and(|id| !rules.contains_key(&id)))
+        {
+            return Err(ParseError::new(unknown_id, "Rule not found!"));
+        }
+        if !rules.contains_key(&0) {
+            return Err(ParseError::new(&s[s.len()..], "Missing rule 0!"));
+        }
+
+        Ok(Rules { rules })
+    }
+}
+
+fn parse_reg_tex(input: &str) -> Result<(Rules, Vec<&str>), ParseError> {
+    let mut sections = blocks(input);
+    let (rules, messages) = sections
+        .next()
+        .zip(sections.next())
+        .ok_or_else(|| ParseError::new(&input[input.len()..], "Missing the messages!"))?;
+    Ok((rules.parse()?, lines(messages).collect()))
+}
+
+fn solve_part_one(rules: &Rules, messages: &[&str]) -> usize {
+    messages
+        .iter()
+        .filter(|message| rules.check_non_recursive(message))
+        .count()
+}
+
+fn solve_part_two(rules: &Rules, messages: &[&str]) -> usize {
+    let mut rules = rules.clone();
+    rules.patch_rules_part_2();
+    messages
+        .iter()
+        .filter(|message| rules.check(message))
+        .count()
+}
+
+pub type Input<'a> = (Rules, Vec<&'a str>);
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_reg_tex(input)
+}
+
+pub fn part_one((rules, messages): &Input<'_>) -> usize {
+    solve_part_one(rules, messages)
+}
+
+pub fn part_two((rules, messages): &Input<'_>) -> usize {
+    solve_part_two(rules, messages)
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 19;
+    const DATA_FILE: &'static str = "19_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "{} messages completely match rule 0.",
+        "With patched rules, {} messages completely match rule 0.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> usize {
+        self::part_one(input)
+    }
+
+    fn part_two(input: &Self::Input<'_>) -> usize {
+        self::part_two(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba";
+
+    #[test]
+    fn part1_examples() {
+        let (rules, messages) = parse_reg_tex(
+            "\
+0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+
+ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb",
+        )
+        .unwrap();
+        assert_eq!(
+            messages
+                .iter()
+                .map(|message| rules.check_non_recursive(message))
+                .collect::<Vec<_>>(),
+            vec![true, false, true, false, false]
+        );
+        assert_eq!(solve_part_one(&rules, &messages), 2);
+
+        let (rules, messages) = parse_reg_tex(EXAMPLE).unwrap();
+        assert_eq!(solve_part_one(&rules, &messages), 3);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let (rules, messages) = parse_reg_tex(EXAMPLE).unwrap();
+        assert_eq!(solve_part_two(&rules, &messages), 12);
+    }
+
+    #[test]
+    fn checks_all_paths() {
+        let rules = "0: 1 | 1 0\n1: \"a\"".parse::<Rules>().unwrap();
+        assert!(rules.check("a"));
+        assert!(rules.check("aaaa"));
+        assert!(!rules.check("aab"));
+        assert!(!rules.check(""));
+
+        let rules = "0: 1 2 | 1 0 2\n1: \"a\"\n2: \"b\""
+            .parse::<Rules>()
+            .unwrap();
+        assert!(rules.check("aaabbb"));
+        assert!(!rules.check("aaabb"));
+    }
+
+    #[test]
+    fn checks_deeply_looping_rules() {
+        // Nests rule 0 once per pair, deeper than the stack of a test thread allows.
+        let rules = "0: 1 2 | 1 0 2\n1: \"a\"\n2: \"b\""
+            .parse::<Rules>()
+            .unwrap();
+        let pairs = 10000;
+        assert!(rules.check(&("a".repeat(pairs) + &"b".repeat(pairs))));
+    }
+
+    #[test]
+    fn rejects_unknown_rules() {
+        assert!("0: 1 2\n1: \"a\"".parse::<Rules>().is_err());
+        assert!("1: \"a\"".parse::<Rules>().is_err());
+        assert!("0: \"ab\"".parse::<Rules>().is_err());
+    }
+}
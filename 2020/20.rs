@@ -0,0 +1,238 @@
+use crate::utils::grid::{Grid, Side};
+use crate::utils::parse::{blocks, parse_value, split_once, ParseError};
+use crate::utils::solution::{Solution, Unsolved};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Tile {
+    content: Grid<bool>,
+    borders: [u16; 4],
+    adjacent_borders: HashSet<u16>,
+}
+
+impl Tile {
+    fn new(content: Grid<bool>) -> Self {
+        let border_ids = [Side::Top, Side::Bottom, Side::Left, Side::Right]
+            .map(|side| border_to_ids(&content.border(side)));
+
+        let borders = border_ids.map(|(id, _reversed_id)| id);
+        let adjacent_borders = border_ids
+            .into_iter()
+            .flat_map(|(id, reversed_id)| [id, reversed_id])
+            .collect();
+
+        Tile {
+            content,
+            borders,
+            adjacent_borders,
+        }
+    }
+
+    pub fn get_borders(&self) -> Vec<u16> {
+        self.borders.to_vec()
+    }
+
+    pub fn is_adjacent(&self, other: &Self) -> bool {
+        other
+            .get_borders()
+            .into_iter()
+            .any(|other_border| self.adjacent_borders.contains(&other_border))
+    }
+}
+
+fn border_to_ids(border: &[&bool]) -> (u16, u16) {
+    let id = border
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (index, val)| acc | ((**val as u16) << index));
+
+    (id, id.reverse_bits() >> 6)
+}
+
+impl FromStr for Tile {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let content = Grid::parse(s, |character| match character {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        if (content.width(), content.height()) != (10, 10) {
+            return Err(ParseError::new(s, "A tile has to be 10x10 pixels!"));
+        }
+        Ok(Tile::new(content))
+    }
+}
+
+type Tiles = HashMap<u16, Tile>;
+
+fn parse_tiles(input: &str) -> Result<Tiles, ParseError> {
+    blocks(input)
+        .map(|tile_block| {
+            let (header, tile) = split_once(tile_block, "\n")?;
+            let header = header.trim_end();
+            let tile_id = header
+                .strip_prefix("Tile ")
+                .and_then(|tile_id| tile_id.strip_suffix(':'))
+                .ok_or_else(|| ParseError::new(header, "Expected `Tile <ID>:`!"))?;
+            Ok((parse_value(tile_id)?, tile.parse()?))
+        })
+        .collect()
+}
+
+fn get_corner_tiles(tiles: &Tiles) -> Vec<u16> {
+    tiles
+        .iter()
+        .filter(|(tile_id, tile)| {
+            tiles
+                .iter()
+                .filter(|(other_tile_id, other_tile)| {
+                    tile_id != other_tile_id && tile.is_adjacent(other_tile)
+                })
+                .take(3)
+                .count()
+                == 2
+        })
+        .map(|(tile_id, _tile)| *tile_id)
+        .take(4)
+        .collect()
+}
+
+fn solve_part_one(tiles: &Tiles) -> u64 {
+    get_corner_tiles(tiles)
+        .into_iter()
+        .map(|tile_id| tile_id as u64)
+        .product()
+}
+
+pub type Input<'a> = Tiles;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_tiles(input)
+}
+
+pub fn part_one(tiles: &Input<'_>) -> u64 {
+    solve_part_one(tiles)
+}
+
+pub fn part_two(_tiles: &Input<'_>) -> Unsolved {
+    Unsolved
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 20;
+    const DATA_FILE: &'static str = "20_data.raw";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The product of the IDs of the four corner tiles is {}.",
+        "The water roughness is {}.",
+    ];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u64;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(tiles: &Self::Input<'_>) -> u64 {
+        self::part_one(tiles)
+    }
+
+    fn part_two(tiles: &Self::Input<'_>) -> Unsolved {
+        self::part_two(tiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cuts a pseudo-random image into 3x3 tiles, which share their borders like
+    /// in the puzzle, and rotates or flips every tile differently.
+    fn example_tiles() -> String {
+        let mut seed = 2020_u32;
+        let image = Grid::new(
+            28,
+            28,
+            (0..28 * 28)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+                    (seed >> 16) & 1 == 1
+                })
+                .collect(),
+        );
+        (0..9)
+            .map(|index| {
+                let (left, top) = (index % 3 * 9, index / 3 * 9);
+                let tile = Grid::new(
+                    10,
+                    10,
+                    (0..100)
+                        .map(|pixel| image[(left + pixel % 10, top + pixel / 10)])
+                        .collect(),
+                );
+                let pixels = tile.orientations()[index % 8]
+                    .rows()
+                    .map(|row| {
+                        row.iter()
+                            .map(|pixel| if *pixel { '#' } else { '.' })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                format!("Tile {}:\n{}", 1000 + index, pixels)
+            })
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    #[test]
+    fn part1_examples() {
+        let tiles = parse_tiles(&example_tiles()).unwrap();
+        assert_eq!(tiles.len(), 9);
+        assert!(tiles[&1000].is_adjacent(&tiles[&1001]));
+        assert!(tiles[&1004].is_adjacent(&tiles[&1007]));
+        assert!(!tiles[&1000].is_adjacent(&tiles[&1008]));
+
+        let mut corners = get_corner_tiles(&tiles);
+        corners.sort_unstable();
+        assert_eq!(corners, vec![1000, 1002, 1006, 1008]);
+        assert_eq!(solve_part_one(&tiles), 1000 * 1002 * 1006 * 1008);
+    }
+
+    #[test]
+    fn reads_borders() {
+        let tile = "\
+#.........
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+##.......#"
+            .parse::<Tile>()
+            .unwrap();
+        assert_eq!(
+            tile.get_borders(),
+            vec![0b1, 0b10_0000_0011, 0b10_0000_0001, 0b10_0000_0000]
+        );
+        assert!(tile.adjacent_borders.contains(&0b10_0000_0000));
+        assert!(tile.adjacent_borders.contains(&0b1));
+        assert!(tile.adjacent_borders.contains(&0b11_0000_0001));
+    }
+
+    #[test]
+    fn rejects_invalid_tiles() {
+        assert!(parse_tiles("Tile 1:\n#.\n.#").is_err());
+        assert!(parse_tiles("Tile:\n").is_err());
+    }
+}
@@ -0,0 +1,295 @@
+use crate::utils::parse::{blocks, lines, parse_value, split_once, ParseError};
+use crate::utils::random::Rng;
+use crate::utils::solution::{Generator, Solution};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+type Cards = VecDeque<u32>;
+type Decks = HashMap<String, Cards>;
+
+fn parse_decks(input: &str) -> Result<Decks, ParseError> {
+    let mut decks = Decks::new();
+    for deck in blocks(input) {
+        let (header, cards) = split_once(deck, "\n")?;
+        let header = header.trim_end();
+        let player = header
+            .strip_suffix(':')
+            .ok_or_else(|| ParseError::new(header, "Expected `<Player>:`!"))?;
+        if player != "Player 1" && player != "Player 2" {
+            return Err(ParseError::new(player, "Expected Player 1 or Player 2!"));
+        }
+        let cards = lines(cards)
+            .map(parse_value)
+            .collect::<Result<Cards, _>>()?;
+        if decks.insert(player.to_string(), cards).is_some() {
+            return Err(ParseError::new(player, "Duplicate deck!"));
+        }
+    }
+    for player in ["Player 1", "Player 2"] {
+        if !decks.contains_key(player) {
+            let reason = format!("Missing {}!", player);
+            return Err(ParseError::new(&input[input.len()..], reason));
+        }
+    }
+    Ok(decks)
+}
+
+fn get_winner(decks: &Decks) -> (String, Cards) {
+    let mut decks = decks.clone();
+    while decks.iter().all(|(_name, cards)| !cards.is_empty()) {
+        let (round_winner, mut round_cards) = decks.iter_mut().fold(
+            (String::new(), VecDeque::new()),
+            |(round_winner, mut round_cards), (player, players_deck)| {
+                let players_round_card = players_deck.pop_front().expect("Empty deck!");
+                if round_cards
+                    .iter()
+                    .any(|round_card| *round_card > players_round_card)
+                {
+                    round_cards.push_back(players_round_card);
+                    (round_winner, round_cards)
+                } else {
+                    round_cards.push_front(players_round_card);
+                    (player.to_string(), round_cards)
+                }
+            },
+        );
+        let round_winner = round_winner.clone();
+        decks
+            .entry(round_winner)
+            .and_modify(|players_deck| players_deck.append(&mut round_cards));
+    }
+    decks
+        .into_iter()
+        .find(|(_player, cards)| !cards.is_empty())
+        .expect("No winner!")
+}
+
+fn get_recursive_winner(
+    mut decks: Decks,
+    mut previous_decks: HashMap<String, HashSet<Cards>>,
+    outer: bool,
+) -> (String, Cards) {
+    if !outer
+        && decks["Player 1"].iter().max()
+            == decks
+                .values()
+                .map(|deck| deck.iter().max())
+                .max()
+                .expect("No player!")
+    {
+        return ("Player 1".to_string(), VecDeque::new());
+    }
+    while decks.iter().all(|(_name, cards)| !cards.is_empty()) {
+        if decks
+            .iter()
+            .any(|(player, cards)| previous_decks.get(player).unwrap().contains(cards))
+        {
+            return decks
+                .into_iter()
+                .find(|(player, _cards)| *player == "Player 1")
+                .expect("No winner!");
+        }
+        decks.iter().for_each(|(player, cards)| {
+            previous_decks
+                .entry(player.to_string())
+                .and_modify(|previous_cards| {
+                    previous_cards.insert(cards.clone());
+                });
+        });
+        let (recursive_round, round_winner, mut round_cards, subdecks) = decks.iter_mut().fold(
+            (true, String::new(), VecDeque::new(), HashMap::new()),
+            |(recursive_round, round_winner, mut round_cards, mut subdecks),
+             (player, players_deck)| {
+                let players_round_card = players_deck.pop_front().expect("Empty deck!");
+                subdecks.insert(
+                    player.to_string(),
+                    players_deck
+                        .iter()
+                        .take(players_round_card as usize)
+                        .cloned()
+                        .collect(),
+                );
+                if round_cards
+                    .iter()
+                    .any(|round_card| *round_card > players_round_card)
+                {
+                    round_cards.push_back(players_round_card);
+                    (
+                        recursive_round && (players_round_card <= players_deck.len() as u32),
+                        round_winner,
+                        round_cards,
+                        subdecks,
+                    )
+                } else {
+                    round_cards.push_front(players_round_card);
+                    (
+                        recursive_round && (players_round_card <= players_deck.len() as u32),
+                        player.to_string(),
+                        round_cards,
+                        subdecks,
+                    )
+                }
+            },
+        );
+        let round_winner = match recursive_round {
+            true => {
+                let (submatch_winner, _deck) =
+                    get_recursive_winner(subdecks, previous_decks.clone(), false);
+                if round_winner != submatch_winner {
+                    round_cards = round_cards.into_iter().rev().collect();
+                }
+                submatch_winner
+            }
+            false => round_winner.clone(),
+        };
+        decks
+            .entry(round_winner)
+            .and_modify(|players_deck| players_deck.append(&mut round_cards));
+    }
+    decks
+        .into_iter()
+        .find(|(_player, cards)| !cards.is_empty())
+        .expect("No winner!")
+}
+
+fn get_score(cards: &Cards) -> u32 {
+    cards
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(factor, card)| *card * (factor as u32 + 1))
+        .sum()
+}
+
+fn solve_part_one(decks: &Decks) -> u32 {
+    let (_winner, cards) = get_winner(decks);
+    get_score(&cards)
+}
+
+fn solve_part_two(decks: &Decks) -> u32 {
+    let (_winner, cards) = get_recursive_winner(
+        decks.clone(),
+        decks
+            .keys()
+            .map(|player| (player.clone(), HashSet::new()))
+            .collect(),
+        true,
+    );
+    get_score(&cards)
+}
+
+/// Generates two decks of `size` cards each, which are dealt from the shuffled
+/// cards `1..=2 * size`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut cards = (1..=2 * size)
+        .map(|card| card.to_string())
+        .collect::<Vec<_>>();
+    rng.shuffle(&mut cards);
+    let (first, second) = cards.split_at(size);
+    format!(
+        "Player 1:\n{}\n\nPlayer 2:\n{}",
+        first.join("\n"),
+        second.join("\n")
+    )
+}
+
+pub type Input<'a> = Decks;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_decks(input)
+}
+
+pub fn part_one(decks: &Input<'_>) -> u32 {
+    solve_part_one(decks)
+}
+
+pub fn part_two(decks: &Input<'_>) -> u32 {
+    solve_part_two(decks)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 22;
+    const DATA_FILE: &'static str = "22_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "The winner achieved a score of {}.",
+        "The winner achieved a score of {} at a recursive game.",
+    ];
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    type Input<'a> = Input<'a>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(decks: &Self::Input<'_>) -> u32 {
+        self::part_one(decks)
+    }
+
+    fn part_two(decks: &Self::Input<'_>) -> u32 {
+        self::part_two(decks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "Player 1:\n9\n2\n6\n3\n1\n\nPlayer 2:\n5\n8\n4\n7\n10";
+
+    #[test]
+    fn part1_examples() {
+        let decks = parse_decks(EXAMPLE).unwrap();
+        let (winner, cards) = get_winner(&decks);
+        assert_eq!(winner, "Player 2");
+        assert_eq!(cards, VecDeque::from(vec![3, 2, 10, 6, 8, 5, 9, 4, 7, 1]));
+        assert_eq!(get_score(&cards), 306);
+        assert_eq!(solve_part_one(&decks), 306);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let decks = parse_decks(EXAMPLE).unwrap();
+        assert_eq!(solve_part_two(&decks), 291);
+
+        let decks = parse_decks("Player 1:\n43\n19\n\nPlayer 2:\n2\n29\n14").unwrap();
+        let (winner, _cards) = get_recursive_winner(
+            decks.clone(),
+            decks
+                .keys()
+                .map(|player| (player.clone(), HashSet::new()))
+                .collect(),
+            true,
+        );
+        assert_eq!(winner, "Player 1");
+    }
+
+    #[test]
+    fn rejects_missing_player() {
+        assert!(parse_decks("Player 2:\n1").is_err());
+        assert!(parse_decks("Player 1\n1").is_err());
+        assert!(parse_decks("Player 1:\n1\n2").is_err());
+    }
+
+    #[test]
+    fn rejects_extra_players() {
+        assert!(parse_decks("Player 1:\n1\n\nPlayer 1:\n2\n\nPlayer 2:\n3").is_err());
+        assert!(parse_decks("Player 1:\n1\n\nPlayer 2:\n2\n\nPlayer 3:\n3").is_err());
+    }
+
+    #[test]
+    fn plays_generated_decks() {
+        for (seed, size) in [(0, 1), (1, 10), (2, 25)] {
+            let decks = parse(&generate(&mut Rng::new(seed), size)).unwrap();
+            assert!(decks.values().all(|cards| cards.len() == size));
+            let (_winner, cards) = get_winner(&decks);
+            assert_eq!(cards.len(), 2 * size);
+            assert!(part_two(&decks) > 0);
+        }
+    }
+}
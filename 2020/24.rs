@@ -0,0 +1,208 @@
+use crate::utils::automaton::{Automaton, Rule};
+use crate::utils::parse::{lines, ParseError};
+use crate::utils::random::Rng;
+use crate::utils::solution::{Generator, Solution};
+use std::collections::{BTreeSet, HashMap};
+
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone)]
+pub struct Coordinate {
+    x: i32,
+    y: i32,
+}
+
+impl Coordinate {
+    fn from_directions(directions: &str) -> Result<Self, ParseError> {
+        let mut remaining = directions;
+        while let Some(character) = remaining.chars().next() {
+            let step = ["ne", "nw", "se", "sw", "e", "w"]
+                .iter()
+                .find(|step| remaining.starts_with(*step))
+                .ok_or_else(|| {
+                    ParseError::new(&remaining[..character.len_utf8()], "Unknown direction!")
+                })?;
+            remaining = &remaining[step.len()..];
+        }
+
+        let north_west_steps = directions.matches("nw").count() as i32;
+        let north_east_steps = directions.matches("ne").count() as i32;
+        let south_west_steps = directions.matches("sw").count() as i32;
+        let south_east_steps = directions.matches("se").count() as i32;
+        let directions = directions
+            .replace("nw", "")
+            .replace("ne", "")
+            .replace("sw", "")
+            .replace("se", "");
+        let east_steps = directions.matches('e').count() as i32;
+        let west_steps = directions.matches('w').count() as i32;
+
+        Ok(Coordinate {
+            x: east_steps - west_steps + north_east_steps - south_west_steps,
+            y: north_west_steps - south_east_steps + north_east_steps - south_west_steps,
+        })
+    }
+
+    fn neighborhood(&self) -> impl Iterator<Item = Self> {
+        let center = self.clone();
+        [(1, 0), (1, 1), (0, 1), (-1, 0), (-1, -1), (0, -1)]
+            .into_iter()
+            .map(move |(x_offset, y_offset)| Coordinate {
+                x: center.x + x_offset,
+                y: center.y + y_offset,
+            })
+    }
+}
+
+fn parse_tiles(input: &str) -> Result<Vec<Coordinate>, ParseError> {
+    lines(input).map(Coordinate::from_directions).collect()
+}
+
+fn get_black_tiles(flipped_tiles: Vec<Coordinate>) -> BTreeSet<Coordinate> {
+    flipped_tiles
+        .into_iter()
+        .fold(HashMap::new(), |mut tiles, coordinate| {
+            tiles
+                .entry(coordinate)
+                .and_modify(|flips| *flips += 1)
+                .or_insert(1_usize);
+            tiles
+        })
+        .into_iter()
+        .filter(|(_coordinate, flips)| flips % 2 == 1)
+        .map(|(coordinate, _flips)| coordinate)
+        .collect()
+}
+
+fn solve_part_one(black_tiles: &BTreeSet<Coordinate>) -> usize {
+    black_tiles.len()
+}
+
+fn solve_part_two(black_tiles: &BTreeSet<Coordinate>) -> usize {
+    let mut automaton = Automaton::new(
+        black_tiles.iter().cloned(),
+        Coordinate::neighborhood,
+        Rule::new(&[2], &[1, 2]),
+    );
+    automaton.run(100);
+    automaton.population()
+}
+
+/// Generates `size` walks of up to 20 random steps from the reference tile.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(1))
+        .map(|_| {
+            (0..rng.range(1..21))
+                .map(|_| *rng.choose(&["e", "se", "sw", "w", "nw", "ne"]))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub type Input<'a> = BTreeSet<Coordinate>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    Ok(get_black_tiles(parse_tiles(input)?))
+}
+
+pub fn part_one(black_tiles: &Input<'_>) -> usize {
+    solve_part_one(black_tiles)
+}
+
+pub fn part_two(black_tiles: &Input<'_>) -> usize {
+    solve_part_two(black_tiles)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 24;
+    const DATA_FILE: &'static str = "24_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = [
+        "{} tiles are left with the black side up.",
+        "After 100 days, there are {} tiles left with the black side up.",
+    ];
+    const GENERATOR: Option<Generator> = Some(generate);
+
+    type Input<'a> = Input<'a>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(black_tiles: &Self::Input<'_>) -> usize {
+        self::part_one(black_tiles)
+    }
+
+    fn part_two(black_tiles: &Self::Input<'_>) -> usize {
+        self::part_two(black_tiles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+sesenwnenenewseeswwswswwnenewsewsw
+neeenesenwnwwswnenewnwwsewnenwseswesw
+seswneswswsenwwnwse
+nwnwneseeswswnenewneswwnewseswneseene
+swweswneswnenwsewnwneneseenw
+eesenwseswswnenwswnwnwsewwnwsene
+sewnenenenesenwsewnenwwwse
+wenwwweseeeweswwwnwwe
+wsweesenenewnwwnwsenewsenwwsesesenwne
+neeswseenwwswnwswswnw
+nenwswwsewswnenenewsenwsenwnesesenew
+enewnwewneswsewnwswenweswnenwsenwsw
+sweneswneswneneenwnewenewwneswswnese
+swwesenesewenwneswnwwneseswwne
+enesenwswwswneneswsenwnewswseenwsese
+wnwnesenesenenwwnenwsewesewsesesew
+nenewswnwewswnenesenwnesewesw
+eneswnwswnwsenenwnwnwwseeswneewsenese
+neswnwewnwnwseenwseesewsenwsweewe
+wseweeenwnesenwwwswnew";
+
+    #[test]
+    fn part1_examples() {
+        assert_eq!(
+            Coordinate::from_directions("nwwswee").unwrap(),
+            Coordinate { x: 0, y: 0 }
+        );
+        assert_eq!(
+            Coordinate::from_directions("esew").unwrap(),
+            Coordinate::from_directions("se").unwrap()
+        );
+
+        let black_tiles = get_black_tiles(parse_tiles(EXAMPLE).unwrap());
+        assert_eq!(solve_part_one(&black_tiles), 10);
+    }
+
+    #[test]
+    fn part2_examples() {
+        let black_tiles = get_black_tiles(parse_tiles(EXAMPLE).unwrap());
+        assert_eq!(solve_part_two(&black_tiles), 2208);
+    }
+
+    #[test]
+    fn rejects_unknown_directions() {
+        assert!(parse_tiles("nwwswee\nnwx").is_err());
+        assert!(parse_tiles("n").is_err());
+    }
+
+    #[test]
+    fn flips_generated_tiles() {
+        for (seed, size) in [(0, 1), (1, 20), (2, 500)] {
+            let flipped_tiles = parse_tiles(&generate(&mut Rng::new(seed), size)).unwrap();
+            assert_eq!(flipped_tiles.len(), size);
+            // Every walk flips one tile, so the parity of the black tiles is the walks'.
+            let black_tiles = solve_part_one(&get_black_tiles(flipped_tiles));
+            assert!(black_tiles <= size);
+            assert_eq!(black_tiles % 2, size % 2);
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::utils::maths::{discrete_logarithm, ModInt};
+use crate::utils::parse::{lines, parse_value, ParseError};
+use crate::utils::solution::{Solution, Unsolved};
+
+/// The public keys of the handshake, which transform the subject number modulo the modulus.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Handshake {
+    pub card_public_key: u64,
+    pub door_public_key: u64,
+    pub subject: u64,
+    pub modulus: u64,
+}
+
+/// Parses the public keys, which may be followed by the subject number and the
+/// modulus on a third line, e.g. `7 20201227` like in the puzzle.
+fn parse_handshake(input: &str) -> Result<Handshake, ParseError> {
+    let mut lines = lines(input);
+    let (card_public_key, door_public_key) = lines
+        .next()
+        .zip(lines.next())
+        .ok_or_else(|| ParseError::new(&input[input.len()..], "Missing the door's key!"))?;
+    let (subject, modulus) = match lines.next() {
+        Some(line) => {
+            let mut fields = line.split_whitespace();
+            let (subject, modulus) = match (fields.next(), fields.next(), fields.next()) {
+                (Some(subject), Some(modulus), None) => (subject, modulus),
+                _ => return Err(ParseError::new(line, "Expected `<subject> <modulus>`!")),
+            };
+            match parse_value(modulus)? {
+                0 => return Err(ParseError::new(modulus, "The modulus has to be positive!")),
+                modulus => (parse_value(subject)?, modulus),
+            }
+        }
+        None => (7, 20201227),
+    };
+    Ok(Handshake {
+        card_public_key: parse_value(card_public_key)?,
+        door_public_key: parse_value(door_public_key)?,
+        subject,
+        modulus,
+    })
+}
+
+/// Break Diffie-Hellman: the card's loop size is its key's discrete logarithm, which
+/// transforms the door's key into the encryption key.
+fn solve_part_one(handshake: &Handshake) -> Result<u64, String> {
+    let card_loop_size = discrete_logarithm(
+        handshake.subject,
+        handshake.card_public_key,
+        handshake.modulus,
+    )
+    .ok_or("No loop size transforms the subject into the card's key!")?;
+    Ok(ModInt::new(handshake.door_public_key, handshake.modulus)
+        .pow(card_loop_size)
+        .value())
+}
+
+pub type Input<'a> = Handshake;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_handshake(input)
+}
+
+pub fn part_one(handshake: &Input<'_>) -> Result<u64, String> {
+    solve_part_one(handshake)
+}
+
+pub fn part_two(_public_keys: &Input<'_>) -> Unsolved {
+    Unsolved
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2020;
+    const DAY: u8 = 25;
+    const DATA_FILE: &'static str = "25_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = ["The encryption key is {}.", "The second part is {}."];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = Result<u64, String>;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(input: &Self::Input<'_>) -> Result<u64, String> {
+        self::part_one(input)
+    }
+
+    fn part_two(public_keys: &Self::Input<'_>) -> Unsolved {
+        self::part_two(public_keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::maths::{is_prime, multiplicative_order};
+
+    #[test]
+    fn part1_examples() {
+        let card_exponent = discrete_logarithm(7, 13233401, 20201227).expect("Card invalid!");
+        let door_exponent = discrete_logarithm(7, 6552760, 20201227).expect("Door invalid!");
+
+        assert_eq!(card_exponent, 16679169);
+        assert_eq!(door_exponent, 7725933);
+    }
+
+    #[test]
+    fn simple_examples() {
+        assert_eq!(discrete_logarithm(2, 3, 5).unwrap(), 3);
+        assert!(discrete_logarithm(3, 7, 11).is_none());
+    }
+
+    #[test]
+    fn handshake_examples() {
+        assert_eq!(discrete_logarithm(7, 5764801, 20201227), Some(8));
+        assert_eq!(discrete_logarithm(7, 17807724, 20201227), Some(11));
+        let key = |public_key| ModInt::new(public_key, 20201227);
+        assert_eq!(key(17807724).pow(8), key(14897079));
+        assert_eq!(key(5764801).pow(11), key(14897079));
+        let handshake = parse_handshake("5764801\n17807724").unwrap();
+        assert_eq!((handshake.subject, handshake.modulus), (7, 20201227));
+        assert_eq!(solve_part_one(&handshake), Ok(14897079));
+    }
+
+    #[test]
+    fn handshake_group() {
+        // 7 generates every public key modulo the prime, so each has one loop size.
+        assert!(is_prime(20201227));
+        assert_eq!(multiplicative_order(7, 20201227), Some(20201226));
+    }
+
+    #[test]
+    fn cracks_other_groups() {
+        // 3^5 ≡ 15 and 3^7 ≡ 2 (mod 19), so the key is 3^35 ≡ 2^5 ≡ 13.
+        let handshake = parse_handshake("15\n2\n3  19").unwrap();
+        assert_eq!(solve_part_one(&handshake), Ok(13));
+        // 4 only generates the squares modulo 7, which 3 isn't.
+        let handshake = parse_handshake("3\n2\n4 7").unwrap();
+        assert!(solve_part_one(&handshake).is_err());
+        // A 60-bit prime, whose group order has a large prime factor.
+        let (subject, modulus) = (2, 1_000_000_000_000_000_003);
+        let key = |loop_size| ModInt::new(subject, modulus).pow(loop_size).value();
+        let input = format!(
+            "{}\n{}\n{} {}",
+            key(987_654_321),
+            key(123_456_789),
+            subject,
+            modulus
+        );
+        let handshake = parse_handshake(&input).unwrap();
+        assert_eq!(
+            solve_part_one(&handshake),
+            Ok(ModInt::new(key(123_456_789), modulus)
+                .pow(987_654_321)
+                .value())
+        );
+        assert!(parse_handshake("15\n11\n3").is_err());
+        assert!(parse_handshake("15\n11\n3 0").is_err());
+        assert!(parse_handshake("15\n11\n3 19 1").is_err());
+    }
+
+    #[test]
+    fn discrete_logarithm_edge_cases() {
+        // The smallest exponent is found, even if the base isn't a generator.
+        for (base, result, divider, exponent) in [(2, 1, 5, 0), (6, 6, 7, 1), (7, 1, 20201227, 0)] {
+            assert_eq!(discrete_logarithm(base, result, divider), Some(exponent));
+        }
+    }
+}
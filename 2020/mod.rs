@@ -0,0 +1,103 @@
+use crate::utils::solution::{day, Day};
+
+#[path = "01.rs"]
+pub mod day01;
+#[path = "02.rs"]
+pub mod day02;
+#[path = "03.rs"]
+pub mod day03;
+#[path = "04.rs"]
+pub mod day04;
+#[path = "05.rs"]
+pub mod day05;
+#[path = "06.rs"]
+pub mod day06;
+#[path = "07.rs"]
+pub mod day07;
+#[path = "08.rs"]
+pub mod day08;
+#[path = "09.rs"]
+pub mod day09;
+#[path = "10.rs"]
+pub mod day10;
+#[path = "11.rs"]
+pub mod day11;
+#[path = "12.rs"]
+pub mod day12;
+#[path = "13.rs"]
+pub mod day13;
+#[path = "14.rs"]
+pub mod day14;
+#[path = "15.rs"]
+pub mod day15;
+#[path = "16.rs"]
+pub mod day16;
+#[path = "17.rs"]
+pub mod day17;
+#[path = "18.rs"]
+pub mod day18;
+#[path = "19.rs"]
+pub mod day19;
+#[path = "20.rs"]
+pub mod day20;
+#[path = "22.rs"]
+pub mod day22;
+#[path = "24.rs"]
+pub mod day24;
+#[path = "25.rs"]
+pub mod day25;
+
+/// One line per day, so new days can be registered in order.
+#[rustfmt::skip]
+pub const DAYS: [Day; 23] = [
+    day::<day01::Day01>(),
+    day::<day02::Day02>(),
+    day::<day03::Day03>(),
+    day::<day04::Day04>(),
+    day::<day05::Day05>(),
+    day::<day06::Day06>(),
+    day::<day07::Day07>(),
+    day::<day08::Day08>(),
+    day::<day09::Day09>(),
+    day::<day10::Day10>(),
+    day::<day11::Day11>(),
+    day::<day12::Day12>(),
+    day::<day13::Day13>(),
+    day::<day14::Day14>(),
+    day::<day15::Day15>(),
+    day::<day16::Day16>(),
+    day::<day17::Day17>(),
+    day::<day18::Day18>(),
+    day::<day19::Day19>(),
+    day::<day20::Day20>(),
+    day::<day22::Day22>(),
+    day::<day24::Day24>(),
+    day::<day25::Day25>(),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::parse::platform_variants;
+    use crate::utils::solution::Part;
+    use std::fs;
+
+    #[test]
+    fn solves_platform_variants() {
+        for day in &DAYS {
+            let answers = |input: &str| {
+                (day.solver)(input, &Part::ALL)
+                    .unwrap_or_else(|err| panic!("{} doesn't parse: {}", day, err))
+                    .answers
+                    .into_iter()
+                    .map(|(part, answer, _cost)| (part, answer))
+                    .collect::<Vec<_>>()
+            };
+            let input = fs::read_to_string(day.data_path()).expect("Data file is missing!");
+            let expected = answers(&input);
+            for variant in platform_variants(&input) {
+                assert_eq!(answers(&variant), expected, "{}", day);
+            }
+        }
+    }
+}
@@ -0,0 +1,316 @@
+//! The command line front-end of the library in `lib.rs`.
+
+use aoc::utils::alloc::CountingAllocator;
+use aoc::utils::bench::{bench, BenchArgs, BenchOptions};
+use aoc::utils::config::{load_config, Config};
+use aoc::utils::fetch::{fetch, FetchArgs};
+use aoc::utils::input::InputSource;
+use aoc::utils::manifest::{verify, VerifyArgs, ANSWERS_FILE};
+use aoc::utils::runner::{run, Output, RunArgs};
+use aoc::utils::scaffold::new_day;
+use aoc::utils::solution::Part;
+use aoc::utils::submit::{submit, SubmitArgs};
+use aoc::{all_days, latest_year};
+use std::path::PathBuf;
+
+/// Only counts once `--allocations` enables it, otherwise it's the system allocator.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// The seed of generated inputs, unless `--seed` is given.
+const DEFAULT_SEED: u64 = 2020;
+
+const USAGE: &str =
+    "Usage: aoc run [YEAR] [DAY] [--part PART] [--input PATH|-] [--jobs N] [--quiet|--json]
+                     [--allocations]
+       aoc bench [YEAR] [DAY] [--part PART] [--input PATH|-] [--warmup N] [--runs N] [--csv]
+                 [--generate SIZE [--seed N]]
+       aoc verify [YEAR] [DAY] [--part PART] [--answers PATH] [--quiet]
+       aoc fetch [YEAR] [DAY]
+       aoc submit [YEAR] DAY PART
+       aoc new [YEAR] DAY
+A day can also be given as YEAR/DAY, without a year it's one of the latest year.";
+
+/// Parses `YEAR`, `DAY` or `YEAR/DAY` into the selection, where a year has four digits.
+fn parse_selection(arg: &str, year: &mut Option<u16>, day: &mut Option<u8>) -> Result<(), String> {
+    let parse_year = |year: &str| {
+        year.parse()
+            .ok()
+            .filter(|year| (2015..=9999).contains(year))
+            .ok_or_else(|| format!("Invalid year {}!", year))
+    };
+    let parse_day = |day: &str| {
+        day.parse()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or_else(|| format!("Invalid day {}!", day))
+    };
+
+    match arg.split_once('/') {
+        Some((selected_year, selected_day)) if year.is_none() => {
+            *year = Some(parse_year(selected_year)?);
+            *day = Some(parse_day(selected_day)?);
+        }
+        Some(_selection) => return Err(format!("Unexpected argument {}!", arg)),
+        None if year.is_none() && arg.len() == 4 => *year = Some(parse_year(arg)?),
+        None => *day = Some(parse_day(arg)?),
+    }
+    Ok(())
+}
+
+fn parse_run_args(mut args: impl Iterator<Item = String>) -> Result<RunArgs, String> {
+    let mut run_args = RunArgs {
+        year: None,
+        day: None,
+        part: None,
+        input: None,
+        output: Output::Described,
+        jobs: None,
+        allocations: false,
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" | "-p" => {
+                let part = args.next().ok_or("Missing value for --part!")?;
+                run_args.part = Some(
+                    part.parse()
+                        .ok()
+                        .and_then(Part::from_number)
+                        .ok_or_else(|| format!("Invalid part {}!", part))?,
+                );
+            }
+            "--input" | "-i" => {
+                let input = args.next().ok_or("Missing value for --input!")?;
+                run_args.input = Some(InputSource::from_arg(&input));
+            }
+            "--quiet" | "-q" => run_args.output = Output::Quiet,
+            "--json" => run_args.output = Output::Json,
+            "--jobs" | "-j" => {
+                let jobs = args.next().ok_or("Missing value for --jobs!")?;
+                run_args.jobs = Some(
+                    jobs.parse()
+                        .ok()
+                        .filter(|jobs| *jobs > 0)
+                        .ok_or_else(|| format!("Invalid number of jobs {}!", jobs))?,
+                );
+            }
+            "--allocations" => run_args.allocations = true,
+            selection if run_args.day.is_none() => {
+                parse_selection(selection, &mut run_args.year, &mut run_args.day)?
+            }
+            arg => return Err(format!("Unexpected argument {}!", arg)),
+        }
+    }
+    if run_args.day.is_some() && run_args.year.is_none() {
+        run_args.year = Some(latest_year());
+    }
+    if let Some(year) = run_args.year {
+        if !all_days().any(|day| day.year == year) {
+            return Err(format!("Year {} isn't solved!", year));
+        }
+    }
+    if let (Some(year), Some(day)) = (run_args.year, run_args.day) {
+        if !all_days().any(|solved| (solved.year, solved.day) == (year, day)) {
+            return Err(format!("Day {}/{:02} isn't solved!", year, day));
+        }
+    }
+    if run_args.input.is_some() && run_args.day.is_none() {
+        return Err("An input can only be given for a single day!".to_string());
+    }
+    if run_args.allocations && run_args.jobs.is_some() {
+        return Err("Allocations can only be counted one day at a time!".to_string());
+    }
+    if run_args.allocations && run_args.output == Output::Quiet {
+        return Err("Allocations can't be quiet!".to_string());
+    }
+    Ok(run_args)
+}
+
+fn parse_bench_args(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, String> {
+    let parse_count = |flag: &str, value: Option<String>| {
+        let value = value.ok_or_else(|| format!("Missing value for {}!", flag))?;
+        value
+            .parse::<usize>()
+            .map_err(|_| format!("Invalid value {} for {}!", value, flag))
+    };
+
+    let mut options = BenchOptions::default();
+    let mut csv = false;
+    let mut size = None;
+    let mut seed = None;
+    let mut run_args = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--warmup" | "-w" => options.warmup = parse_count("--warmup", args.next())?,
+            "--runs" | "-r" => options.runs = parse_count("--runs", args.next())?,
+            "--csv" => csv = true,
+            "--generate" | "-g" => size = Some(parse_count("--generate", args.next())?),
+            "--seed" => {
+                let value = args.next().ok_or("Missing value for --seed!")?;
+                seed = Some(
+                    value
+                        .parse::<u64>()
+                        .map_err(|_| format!("Invalid value {} for --seed!", value))?,
+                );
+            }
+            _ => run_args.push(arg),
+        }
+    }
+    if options.runs == 0 {
+        return Err("At least one run is needed!".to_string());
+    }
+    let generate = match (size, seed) {
+        (Some(size), seed) => Some((size, seed.unwrap_or(DEFAULT_SEED))),
+        (None, Some(_)) => return Err("A seed is only used with --generate!".to_string()),
+        (None, None) => None,
+    };
+    let run_args = parse_run_args(run_args.into_iter())?;
+    match run_args.output {
+        Output::Described => {}
+        Output::Quiet => return Err("Timings can't be quiet!".to_string()),
+        Output::Json => return Err("Timings are written as CSV instead of JSON!".to_string()),
+    }
+    if run_args.jobs.is_some() {
+        return Err("Days are timed one after another!".to_string());
+    }
+    if run_args.allocations {
+        return Err("Allocations are counted by run instead of bench!".to_string());
+    }
+    if generate.is_some() && run_args.input.is_some() {
+        return Err("An input is either generated or given!".to_string());
+    }
+    Ok(BenchArgs {
+        run_args,
+        options,
+        csv,
+        generate,
+    })
+}
+
+fn parse_verify_args(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs, String> {
+    let mut answers = PathBuf::from(ANSWERS_FILE);
+    let mut run_args = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" | "-a" => {
+                answers = PathBuf::from(args.next().ok_or("Missing value for --answers!")?);
+            }
+            _ => run_args.push(arg),
+        }
+    }
+    let run_args = parse_run_args(run_args.into_iter())?;
+    if run_args.input.is_some() {
+        return Err("The inputs are given by the answers file!".to_string());
+    }
+    if run_args.output == Output::Json {
+        return Err("Verifying can't be written as JSON!".to_string());
+    }
+    if run_args.jobs.is_some() {
+        return Err("Days are verified one after another!".to_string());
+    }
+    if run_args.allocations {
+        return Err("Allocations are counted by run instead of verify!".to_string());
+    }
+    Ok(VerifyArgs { run_args, answers })
+}
+
+fn parse_fetch_args(args: impl Iterator<Item = String>) -> Result<FetchArgs, String> {
+    let (mut year, mut day) = (None, None);
+    for arg in args {
+        if day.is_some() {
+            return Err(format!("Unexpected argument {}!", arg));
+        }
+        parse_selection(&arg, &mut year, &mut day)?;
+    }
+    if day.is_some() && year.is_none() {
+        year = Some(latest_year());
+    }
+    Ok(FetchArgs { year, day })
+}
+
+fn parse_submit_args(args: impl Iterator<Item = String>) -> Result<SubmitArgs, String> {
+    let args = args.collect::<Vec<_>>();
+    let (part, selection) = args.split_last().ok_or("Missing day and part!")?;
+    let part = part
+        .parse()
+        .ok()
+        .and_then(Part::from_number)
+        .ok_or_else(|| format!("Invalid part {}!", part))?;
+
+    let (mut year, mut day) = (None, None);
+    for arg in selection {
+        if day.is_some() {
+            return Err(format!("Unexpected argument {}!", arg));
+        }
+        parse_selection(arg, &mut year, &mut day)?;
+    }
+    let (year, day) = (year.unwrap_or_else(latest_year), day.ok_or("Missing day!")?);
+    let day = all_days()
+        .find(|solved| (solved.year, solved.day) == (year, day))
+        .ok_or_else(|| format!("Day {}/{:02} isn't solved!", year, day))?;
+    Ok(SubmitArgs { day, part })
+}
+
+fn parse_new_args(args: impl Iterator<Item = String>) -> Result<(u16, u8), String> {
+    let (mut year, mut day) = (None, None);
+    for arg in args {
+        if day.is_some() {
+            return Err(format!("Unexpected argument {}!", arg));
+        }
+        parse_selection(&arg, &mut year, &mut day)?;
+    }
+    let day = day.ok_or("Missing day!")?;
+    Ok((year.unwrap_or_else(latest_year), day))
+}
+
+enum Command {
+    Run(RunArgs),
+    Bench(BenchArgs),
+    Verify(VerifyArgs),
+    Fetch(FetchArgs),
+    Submit(SubmitArgs),
+    New(u16, u8),
+}
+
+/// Runs a command needing the settings, failing if they can't be loaded.
+fn with_config(command: impl FnOnce(&Config) -> bool) -> bool {
+    match load_config() {
+        Ok(config) => command(&config),
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1);
+
+    let command = match args.next().as_deref() {
+        Some("run") => parse_run_args(args).map(Command::Run),
+        Some("bench") => parse_bench_args(args).map(Command::Bench),
+        Some("verify") => parse_verify_args(args).map(Command::Verify),
+        Some("fetch") => parse_fetch_args(args).map(Command::Fetch),
+        Some("submit") => parse_submit_args(args).map(Command::Submit),
+        Some("new") => parse_new_args(args).map(|(year, day)| Command::New(year, day)),
+        Some(command) => Err(format!("Unknown command {}!", command)),
+        None => Err("Missing command!".to_string()),
+    };
+
+    let succeeded = match command {
+        Ok(Command::Run(run_args)) => with_config(|config| run(&run_args, config)),
+        Ok(Command::Bench(bench_args)) => with_config(|config| bench(&bench_args, config)),
+        Ok(Command::Verify(verify_args)) => verify(&verify_args),
+        Ok(Command::Fetch(fetch_args)) => with_config(|config| fetch(&fetch_args, config)),
+        Ok(Command::Submit(submit_args)) => with_config(|config| submit(&submit_args, config)),
+        Ok(Command::New(year, day)) => new_day(year, day),
+        Err(err) => {
+            eprintln!("{}\n{}", err, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    if !succeeded {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,24 @@
+//! The solved days as library modules, e.g. `y2020::day07::parse` and
+//! `y2020::day07::part_one`, together with the helpers they share.
+
+pub mod utils;
+
+#[path = "2020/mod.rs"]
+pub mod y2020;
+
+use utils::solution::Day;
+
+/// The days of every year, one line per year, so new years can be registered in order.
+#[rustfmt::skip]
+pub const YEARS: [&[Day]; 1] = [
+    &y2020::DAYS,
+];
+
+pub fn all_days() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+/// The year a day without a year refers to.
+pub fn latest_year() -> u16 {
+    all_days().map(|day| day.year).max().expect("No days!")
+}
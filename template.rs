@@ -1,17 +1,65 @@
+use crate::utils::parse::{lines, ParseError};
+use crate::utils::solution::{Solution, Unsolved};
 
-fn parse_(input: &str) -> {}
+fn parse_lines(input: &str) -> Result<Vec<&str>, ParseError> {
+    Ok(lines(input).collect())
+}
+
+fn solve_part_one(_lines: &[&str]) -> Unsolved {
+    Unsolved
+}
+
+fn solve_part_two(_lines: &[&str]) -> Unsolved {
+    Unsolved
+}
+
+pub type Input<'a> = Vec<&'a str>;
+
+pub fn parse(input: &str) -> Result<Input<'_>, ParseError> {
+    parse_lines(input)
+}
 
-fn solve_part_one() {
+pub fn part_one(lines: &Input<'_>) -> Unsolved {
+    solve_part_one(lines)
 }
 
-fn solve_part_two() {
+pub fn part_two(lines: &Input<'_>) -> Unsolved {
+    solve_part_two(lines)
+}
+
+pub struct Day{{NN}};
+
+impl Solution for Day{{NN}} {
+    const YEAR: u16 = {{YEAR}};
+    const DAY: u8 = {{DAY}};
+    const DATA_FILE: &'static str = "{{NN}}_data.txt";
+    const DESCRIPTIONS: [&'static str; 2] = ["{}", "{}"];
+
+    type Input<'a> = Input<'a>;
+    type PartOne = Unsolved;
+    type PartTwo = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        self::parse(input)
+    }
+
+    fn part_one(lines: &Self::Input<'_>) -> Unsolved {
+        self::part_one(lines)
+    }
+
+    fn part_two(lines: &Self::Input<'_>) -> Unsolved {
+        self::part_two(lines)
+    }
 }
 
-fn main() {
-    let input = include_str!("_data.");
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let  = parse_(&input);
+    const EXAMPLE: &str = "";
 
-    solve_part_one();
-    solve_part_two();
+    #[test]
+    fn parses_example() {
+        assert!(parse_lines(EXAMPLE).is_ok());
+    }
 }
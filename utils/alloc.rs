@@ -0,0 +1,149 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, which counts allocations once counting is enabled.
+///
+/// The counts are shared by all threads, so they're only meaningful while a
+/// single thing is measured at a time.
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            BYTES.fetch_add(size, Ordering::Relaxed);
+            let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+            PEAK.fetch_max(live, Ordering::Relaxed);
+        }
+    }
+
+    fn record_dealloc(size: usize) {
+        if ENABLED.load(Ordering::Relaxed) {
+            // Memory allocated before counting was enabled isn't part of `LIVE`.
+            let _ = LIVE.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |live| {
+                Some(live.saturating_sub(size))
+            });
+        }
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_pointer
+    }
+}
+
+/// Starts counting, which costs a few atomic operations per allocation.
+pub fn enable_counting() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+pub fn is_counting() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// What was allocated while running a routine, all zero if counting is disabled.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub allocations: usize,
+    /// Bytes allocated in total, including ones freed again.
+    pub bytes: usize,
+    /// The most bytes allocated at once, above the ones live before the routine.
+    pub peak: usize,
+}
+
+/// Counts the allocations of `routine`, which requires the `CountingAllocator`
+/// to be the global allocator.
+pub fn count_allocations<T>(routine: impl FnOnce() -> T) -> (T, AllocStats) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = routine();
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, stats)
+}
+
+/// Writes bytes with a binary unit, e.g. `1.50 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.2} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    /// The runner installs its own, which isn't part of the test build.
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn formats_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.50 KiB");
+        assert_eq!(format_bytes(34816 * 1024), "34.00 MiB");
+    }
+
+    #[test]
+    fn counts_allocations() {
+        // Other tests allocate concurrently, so only lower bounds hold.
+        enable_counting();
+        let (_, stats) = count_allocations(|| {
+            let first = black_box(vec![0_u8; 4096]);
+            drop(first);
+            black_box(vec![0_u8; 8192]).len()
+        });
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes >= 4096 + 8192);
+        assert!(stats.peak >= 8192);
+    }
+}
@@ -0,0 +1,183 @@
+use super::bigint::{BigInt, BigUint};
+use super::json::Json;
+use super::solution::Unsolved;
+use std::fmt::{self, Display};
+
+/// The answer of a part, so it can be compared, printed and serialized alike.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i64),
+    /// An integer, which doesn't fit into an `i64`.
+    BigInteger(BigInt),
+    Text(String),
+    Unsolved,
+    /// A part, which can't be solved on the given input, with the reason.
+    Failed(String),
+}
+
+impl Answer {
+    /// Fills the answer into the first `{}` of a sentence describing it.
+    pub fn describe(&self, template: &str) -> String {
+        match self {
+            Answer::Unsolved | Answer::Failed(_) => self.to_string(),
+            answer => template.replacen("{}", &answer.to_string(), 1),
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unsolved => write!(f, "{}", Unsolved),
+            Answer::Failed(reason) => write!(f, "{}", reason),
+        }
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(value: i32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Integer(value.into())
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::from(i128::from(value))
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::from(value as u64)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        i64::try_from(value).map_or_else(|_| Answer::BigInteger(value.into()), Answer::Integer)
+    }
+}
+
+impl From<BigInt> for Answer {
+    fn from(value: BigInt) -> Self {
+        match value.to_i128().map(i64::try_from) {
+            Some(Ok(value)) => Answer::Integer(value),
+            _ => Answer::BigInteger(value),
+        }
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(value: BigUint) -> Self {
+        Answer::from(BigInt::from(value))
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl From<Unsolved> for Answer {
+    fn from(_unsolved: Unsolved) -> Self {
+        Answer::Unsolved
+    }
+}
+
+/// Answers the part or tells why it can't be answered.
+impl<T: Into<Answer>> From<Result<T, String>> for Answer {
+    fn from(result: Result<T, String>) -> Self {
+        result.map_or_else(Answer::Failed, Into::into)
+    }
+}
+
+/// Integers are written as numbers, an unsolved or failed part as `null`.
+impl From<Answer> for Json {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Integer(value) => Json::from(value),
+            Answer::BigInteger(value) => Json::from(value),
+            Answer::Text(text) => Json::from(text),
+            Answer::Unsolved | Answer::Failed(_) => Json::Null,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_integers() {
+        assert_eq!(Answer::from(-3_i32), Answer::Integer(-3));
+        assert_eq!(Answer::from(42_usize), Answer::Integer(42));
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX.into()));
+        assert_eq!(Answer::from(7_i128), Answer::Integer(7));
+        assert_eq!(Answer::from(BigUint::from(7_u32)), Answer::Integer(7));
+        let big = "-123456789012345678901234567890123456789012".parse::<BigInt>();
+        assert_eq!(
+            Answer::from(big.clone().unwrap()),
+            Answer::BigInteger(big.unwrap())
+        );
+    }
+
+    #[test]
+    fn displays_raw_values() {
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(Answer::from("abc,def").to_string(), "abc,def");
+        assert_eq!(Answer::from(Unsolved).to_string(), "unsolved");
+    }
+
+    #[test]
+    fn describes_answers() {
+        assert_eq!(
+            Answer::from(71_i32).describe("The scanning error rate is {}."),
+            "The scanning error rate is 71."
+        );
+        assert_eq!(
+            Answer::Unsolved.describe("The water roughness is {}."),
+            "unsolved"
+        );
+    }
+
+    #[test]
+    fn converts_to_json() {
+        assert_eq!(
+            Json::from(Answer::from(u64::MAX)).to_string(),
+            "18446744073709551615"
+        );
+        assert_eq!(Json::from(Answer::from("a,b")).to_string(), "\"a,b\"");
+        assert_eq!(Json::from(Answer::Unsolved), Json::Null);
+        assert_eq!(Json::from(Answer::Failed("No!".to_string())), Json::Null);
+    }
+
+    #[test]
+    fn converts_results() {
+        assert_eq!(Answer::from(Ok::<u32, String>(3)), Answer::Integer(3));
+        let failed = Answer::from(Err::<u32, String>("No bus row!".to_string()));
+        assert_eq!(failed, Answer::Failed("No bus row!".to_string()));
+        assert_eq!(failed.describe("The bus row is at {}."), "No bus row!");
+    }
+}
@@ -0,0 +1,196 @@
+use super::parse::ParseError;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// The neighbor counts, which let a dead cell be born or a living cell survive.
+///
+/// Written as `B<counts>/S<counts>` with one digit per count, e.g. `B3/S23` for
+/// Conway's Game of Life. Counts above 127 never apply.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    birth: u128,
+    survival: u128,
+}
+
+impl Rule {
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        let to_mask = |counts: &[usize]| {
+            counts
+                .iter()
+                .filter(|count| **count < 128)
+                .fold(0, |mask, count| mask | (1 << count))
+        };
+        Rule {
+            birth: to_mask(birth),
+            survival: to_mask(survival),
+        }
+    }
+
+    pub fn is_born(&self, neighbors: usize) -> bool {
+        neighbors < 128 && self.birth & (1 << neighbors) != 0
+    }
+
+    pub fn survives(&self, neighbors: usize) -> bool {
+        neighbors < 128 && self.survival & (1 << neighbors) != 0
+    }
+
+    pub fn is_alive_next(&self, is_alive: bool, neighbors: usize) -> bool {
+        if is_alive {
+            self.survives(neighbors)
+        } else {
+            self.is_born(neighbors)
+        }
+    }
+}
+
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_counts = |counts: &str| {
+            counts
+                .chars()
+                .map(|count| count.to_digit(10).map(|count| count as usize))
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| ParseError::new(counts, "Counts have to be digits!"))
+        };
+
+        let (birth, survival) = s
+            .split_once('/')
+            .and_then(|(birth, survival)| {
+                Some((birth.strip_prefix('B')?, survival.strip_prefix('S')?))
+            })
+            .ok_or_else(|| ParseError::new(s, "Expected `B<counts>/S<counts>`!"))?;
+        Ok(Rule::new(&parse_counts(birth)?, &parse_counts(survival)?))
+    }
+}
+
+/// A cellular automaton on an unbounded space, which only stores the living cells.
+///
+/// The space is given by the `neighborhood` of a cell, so it works the same for
+/// any number of dimensions or grids like hexagonal ones.
+pub struct Automaton<C, N> {
+    cells: HashSet<C>,
+    neighborhood: N,
+    rule: Rule,
+}
+
+impl<C, N, I> Automaton<C, N>
+where
+    C: Hash + Eq,
+    N: Fn(&C) -> I,
+    I: IntoIterator<Item = C>,
+{
+    pub fn new(cells: impl IntoIterator<Item = C>, neighborhood: N, rule: Rule) -> Self {
+        Automaton {
+            cells: cells.into_iter().collect(),
+            neighborhood,
+            rule,
+        }
+    }
+
+    /// Advances by one generation. Only cells next to a living one are candidates,
+    /// their living neighbors are counted in one pass over the living cells.
+    pub fn step(&mut self) {
+        let mut neighbor_counts = HashMap::with_capacity(self.cells.len() * 4);
+        for cell in &self.cells {
+            for neighbor in (self.neighborhood)(cell) {
+                *neighbor_counts.entry(neighbor).or_insert(0) += 1;
+            }
+        }
+
+        let mut previous = std::mem::take(&mut self.cells);
+        for (cell, neighbors) in neighbor_counts {
+            if self.rule.is_alive_next(previous.remove(&cell), neighbors) {
+                self.cells.insert(cell);
+            }
+        }
+        // Only living cells without any living neighbor are left.
+        if self.rule.survives(0) {
+            self.cells.extend(previous);
+        }
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    pub fn population(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_alive(&self, cell: &C) -> bool {
+        self.cells.contains(cell)
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &C> {
+        self.cells.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Cell = (i32, i32);
+
+    fn life(cells: &[Cell]) -> Automaton<Cell, impl Fn(&Cell) -> Vec<Cell>> {
+        let neighborhood = |(x, y): &Cell| {
+            (-1..=1)
+                .flat_map(|x_offset| (-1..=1).map(move |y_offset| (x + x_offset, y + y_offset)))
+                .filter(|neighbor| neighbor != &(*x, *y))
+                .collect()
+        };
+        Automaton::new(
+            cells.iter().copied(),
+            neighborhood,
+            "B3/S23".parse().unwrap(),
+        )
+    }
+
+    #[test]
+    fn parses_rules() {
+        let rule: Rule = "B36/S023".parse().unwrap();
+        assert_eq!(rule, Rule::new(&[3, 6], &[0, 2, 3]));
+        assert!(rule.is_born(6) && !rule.is_born(2));
+        assert!(rule.survives(0) && !rule.survives(1));
+        assert!(!rule.survives(200));
+
+        let input = "B3/S2x";
+        let error = input.parse::<Rule>().unwrap_err().locate(input);
+        assert_eq!(error.column(), Some(5));
+        assert!("3/23".parse::<Rule>().is_err());
+    }
+
+    #[test]
+    fn blinker_oscillates() {
+        let mut automaton = life(&[(0, -1), (0, 0), (0, 1)]);
+        automaton.step();
+        let mut cells = automaton.cells().copied().collect::<Vec<_>>();
+        cells.sort_unstable();
+        assert_eq!(cells, vec![(-1, 0), (0, 0), (1, 0)]);
+        automaton.step();
+        assert!(automaton.is_alive(&(0, -1)) && !automaton.is_alive(&(1, 0)));
+    }
+
+    #[test]
+    fn glider_keeps_population() {
+        let mut automaton = life(&[(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]);
+        automaton.run(4);
+        assert_eq!(automaton.population(), 5);
+        assert!([(2, 1), (3, 2), (1, 3), (2, 3), (3, 3)]
+            .iter()
+            .all(|cell| automaton.is_alive(cell)));
+    }
+
+    #[test]
+    fn keeps_isolated_cells_surviving_zero() {
+        let neighborhood = |x: &i32| [x - 1, x + 1];
+        let mut automaton = Automaton::new([0, 10], neighborhood, "B/S0".parse().unwrap());
+        automaton.run(3);
+        assert_eq!(automaton.population(), 2);
+    }
+}
@@ -0,0 +1,208 @@
+use super::config::Config;
+use super::random::Rng;
+use super::runner::{selected_days, selected_parts, RunArgs};
+use super::solution::Part;
+use std::fmt::{self, Display};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// What is measured of a day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part {}", part.number()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BenchOptions {
+    /// Runs before measuring, which aren't part of the statistics.
+    pub warmup: usize,
+    pub runs: usize,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            warmup: 3,
+            runs: 10,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    /// The sample standard deviation, which is zero for a single run.
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Panics if there are no durations.
+    pub fn from_durations(mut durations: Vec<Duration>) -> Self {
+        assert!(!durations.is_empty(), "No durations measured!");
+        durations.sort_unstable();
+
+        let runs = durations.len();
+        let mean = durations.iter().sum::<Duration>() / runs as u32;
+        let median = if runs.is_multiple_of(2) {
+            (durations[runs / 2 - 1] + durations[runs / 2]) / 2
+        } else {
+            durations[runs / 2]
+        };
+        let variance = durations
+            .iter()
+            .map(|duration| (duration.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / (runs - 1).max(1) as f64;
+
+        Stats {
+            runs,
+            min: durations[0],
+            mean,
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Times `routine` after warming up, its result is kept from being optimized away.
+pub fn measure<T>(options: &BenchOptions, mut routine: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(routine());
+    }
+    let durations = (0..options.runs.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            black_box(routine());
+            start.elapsed()
+        })
+        .collect();
+    Stats::from_durations(durations)
+}
+
+#[derive(Debug)]
+pub struct BenchArgs {
+    pub run_args: RunArgs,
+    pub options: BenchOptions,
+    pub csv: bool,
+    /// The size of the generated inputs and their seed, instead of the puzzle inputs.
+    pub generate: Option<(usize, u64)>,
+}
+
+/// Times the selected days and prints a table or CSV, like `run` it returns `false` on failures.
+pub fn bench(bench_args: &BenchArgs, config: &Config) -> bool {
+    let parts = selected_parts(&bench_args.run_args);
+
+    if bench_args.csv {
+        println!("year,day,stage,runs,min_ns,mean_ns,median_ns,stddev_ns");
+    } else {
+        println!(
+            "{:<9}{:<8}{:>6}{:>12}{:>12}{:>12}{:>12}",
+            "day", "stage", "runs", "min", "mean", "median", "stddev"
+        );
+    }
+
+    let mut succeeded = true;
+    for (day, source) in selected_days(&bench_args.run_args, config) {
+        let input = match (bench_args.generate, day.generator) {
+            (Some((size, seed)), Some(generator)) => generator(&mut Rng::new(seed), size),
+            // Without a selected day, only the days with a generator are timed.
+            (Some(_), None) if bench_args.run_args.day.is_none() => continue,
+            (Some(_), None) => {
+                eprintln!("Day {} has no input generator!", day);
+                succeeded = false;
+                continue;
+            }
+            (None, _) => match source.read() {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Couldn't read input of {}: {}", day, err);
+                    succeeded = false;
+                    continue;
+                }
+            },
+        };
+        let timings = match (day.bencher)(&input, &parts, &bench_args.options) {
+            Ok(timings) => timings,
+            Err(err) => {
+                eprintln!("Couldn't parse input of {}", err);
+                succeeded = false;
+                continue;
+            }
+        };
+        for (stage, stats) in timings {
+            if bench_args.csv {
+                println!(
+                    "{},{},{},{},{},{},{},{}",
+                    day.year,
+                    day.day,
+                    stage,
+                    stats.runs,
+                    stats.min.as_nanos(),
+                    stats.mean.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.stddev.as_nanos()
+                );
+            } else {
+                println!(
+                    "{:<9}{:<8}{:>6}{:>12}{:>12}{:>12}{:>12}",
+                    day.to_string(),
+                    stage.to_string(),
+                    stats.runs,
+                    format!("{:.2?}", stats.min),
+                    format!("{:.2?}", stats.mean),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.stddev)
+                );
+            }
+        }
+    }
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calculates_stats() {
+        let stats = Stats::from_durations(
+            [4, 2, 8, 6]
+                .into_iter()
+                .map(Duration::from_millis)
+                .collect(),
+        );
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(2));
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.stddev.as_micros(), 2581);
+    }
+
+    #[test]
+    fn single_run_has_no_deviation() {
+        let stats = Stats::from_durations(vec![Duration::from_millis(3)]);
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn measures_every_run() {
+        let mut calls = 0;
+        let stats = measure(&BenchOptions { warmup: 2, runs: 5 }, || calls += 1);
+        assert_eq!(calls, 7);
+        assert_eq!(stats.runs, 5);
+    }
+}
@@ -0,0 +1,610 @@
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::iter::{Product, Sum};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub};
+use std::str::FromStr;
+
+/// The arithmetic some days count with, which are native integers by default, but
+/// can be `BigUint`s or `BigInt`s for exact results on larger inputs.
+pub trait Number:
+    Clone + Add<Output = Self> + Mul<Output = Self> + Sum + Product + From<u32>
+{
+}
+
+impl<N> Number for N where N: Clone + Add<Output = N> + Mul<Output = N> + Sum + Product + From<u32> {}
+
+/// A non-negative integer of any size, whose 32-bit limbs are stored least
+/// significant first and without leading zeros, so zero has none.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        BigUint { limbs }
+    }
+
+    pub fn zero() -> Self {
+        BigUint { limbs: Vec::new() }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn bits(&self) -> u64 {
+        self.limbs.last().map_or(0, |last| {
+            self.limbs.len() as u64 * 32 - u64::from(last.leading_zeros())
+        })
+    }
+
+    fn bit(&self, index: u64) -> bool {
+        (self.limbs[(index / 32) as usize] >> (index % 32)) & 1 == 1
+    }
+
+    /// `self * factor + addend` in place.
+    fn mul_add_u32(&mut self, factor: u32, addend: u32) {
+        let mut carry = u64::from(addend);
+        for limb in &mut self.limbs {
+            let value = u64::from(*limb) * u64::from(factor) + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry != 0 {
+            self.limbs.push(carry as u32);
+        }
+        if factor == 0 {
+            *self = BigUint::from_limbs(std::mem::take(&mut self.limbs));
+        }
+    }
+
+    /// Subtracts a smaller or equal number in place.
+    fn sub_in_place(&mut self, other: &Self) {
+        let mut borrow = false;
+        for (index, limb) in self.limbs.iter_mut().enumerate() {
+            let subtrahend = other.limbs.get(index).copied().unwrap_or(0);
+            if !borrow && index >= other.limbs.len() {
+                break;
+            }
+            let (difference, underflow) = limb.overflowing_sub(subtrahend);
+            let (difference, borrowed) = difference.overflowing_sub(u32::from(borrow));
+            *limb = difference;
+            borrow = underflow || borrowed;
+        }
+        assert!(!borrow, "Subtraction underflow!");
+        *self = BigUint::from_limbs(std::mem::take(&mut self.limbs));
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        (*self >= *other).then(|| {
+            let mut difference = self.clone();
+            difference.sub_in_place(other);
+            difference
+        })
+    }
+
+    /// The quotient and remainder of dividing by a single limb.
+    pub fn div_rem_u32(&self, divisor: u32) -> (Self, u32) {
+        assert!(divisor != 0, "Division by zero!");
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut remainder = 0_u64;
+        for (index, limb) in self.limbs.iter().enumerate().rev() {
+            let value = (remainder << 32) | u64::from(*limb);
+            quotient[index] = (value / u64::from(divisor)) as u32;
+            remainder = value % u64::from(divisor);
+        }
+        (BigUint::from_limbs(quotient), remainder as u32)
+    }
+
+    /// The quotient and remainder, where the remainder is below the divisor.
+    /// Long division in binary, unless the divisor has a single limb.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        match divisor.limbs[..] {
+            [] => panic!("Division by zero!"),
+            [limb] => {
+                let (quotient, remainder) = self.div_rem_u32(limb);
+                return (quotient, BigUint::from(remainder));
+            }
+            _ => {}
+        }
+        let mut quotient = vec![0; self.limbs.len()];
+        let mut remainder = BigUint::zero();
+        for index in (0..self.bits()).rev() {
+            remainder.mul_add_u32(2, u32::from(self.bit(index)));
+            if remainder >= *divisor {
+                remainder.sub_in_place(divisor);
+                quotient[(index / 32) as usize] |= 1 << (index % 32);
+            }
+        }
+        (BigUint::from_limbs(quotient), remainder)
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        (0..exponent).fold(BigUint::from(1_u32), |power, _| &power * self)
+    }
+
+    /// The value, if it fits into an `u128`.
+    pub fn to_u128(&self) -> Option<u128> {
+        (self.limbs.len() <= 4).then(|| {
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0, |value, limb| (value << 32) | u128::from(*limb))
+        })
+    }
+
+    /// The decimal digits, which are split off nine at a time.
+    fn to_decimal(&self) -> String {
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, chunk) = rest.div_rem_u32(1_000_000_000);
+            chunks.push(chunk);
+            rest = quotient;
+        }
+        match chunks.split_last() {
+            None => "0".to_string(),
+            Some((first, rest)) => rest.iter().rev().fold(first.to_string(), |digits, chunk| {
+                format!("{}{:09}", digits, chunk)
+            }),
+        }
+    }
+}
+
+impl From<u32> for BigUint {
+    fn from(value: u32) -> Self {
+        BigUint::from_limbs(vec![value])
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        BigUint::from(u128::from(value))
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(value: u128) -> Self {
+        BigUint::from_limbs((0..4).map(|limb| (value >> (32 * limb)) as u32).collect())
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: Self) -> BigUint {
+        let (long, short) = if self.limbs.len() >= other.limbs.len() {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let mut carry = 0;
+        let mut limbs = long
+            .limbs
+            .iter()
+            .enumerate()
+            .map(|(index, limb)| {
+                let value = u64::from(*limb)
+                    + u64::from(short.limbs.get(index).copied().unwrap_or(0))
+                    + carry;
+                carry = value >> 32;
+                value as u32
+            })
+            .collect::<Vec<_>>();
+        limbs.push(carry as u32);
+        BigUint::from_limbs(limbs)
+    }
+}
+
+/// Panics if the difference would be negative.
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    fn sub(self, other: Self) -> BigUint {
+        self.checked_sub(other).expect("Subtraction underflow!")
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, other: Self) -> BigUint {
+        let mut limbs = vec![0_u32; self.limbs.len() + other.limbs.len()];
+        for (index, left) in self.limbs.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (offset, right) in other.limbs.iter().enumerate() {
+                // At most (2^32 - 1)^2 + 2 * (2^32 - 1) = 2^64 - 1.
+                let value =
+                    u64::from(*left) * u64::from(*right) + u64::from(limbs[index + offset]) + carry;
+                limbs[index + offset] = value as u32;
+                carry = value >> 32;
+            }
+            limbs[index + other.limbs.len()] = carry as u32;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+
+/// Decimal digits without a sign.
+impl FromStr for BigUint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("No digits!".to_string());
+        }
+        if let Some(invalid) = s.chars().find(|character| !character.is_ascii_digit()) {
+            return Err(format!("Invalid digit `{}`!", invalid));
+        }
+        let mut value = BigUint::zero();
+        for chunk in s.as_bytes().chunks(9) {
+            let digits = std::str::from_utf8(chunk).expect("ASCII digits!");
+            value.mul_add_u32(
+                10_u32.pow(chunk.len() as u32),
+                digits.parse().expect("Digits!"),
+            );
+        }
+        Ok(value)
+    }
+}
+
+impl Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(true, "", &self.to_decimal())
+    }
+}
+
+/// A signed integer of any size as its sign and magnitude, where zero isn't negative.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: BigUint,
+}
+
+impl BigInt {
+    pub fn new(negative: bool, magnitude: BigUint) -> Self {
+        BigInt {
+            negative: negative && !magnitude.is_zero(),
+            magnitude,
+        }
+    }
+
+    pub fn zero() -> Self {
+        BigInt::default()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn magnitude(&self) -> &BigUint {
+        &self.magnitude
+    }
+
+    /// The quotient rounded towards zero and the remainder with the sign of
+    /// `self`, like the division of native integers.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let (quotient, remainder) = self.magnitude.div_rem(&divisor.magnitude);
+        (
+            BigInt::new(self.negative != divisor.negative, quotient),
+            BigInt::new(self.negative, remainder),
+        )
+    }
+
+    pub fn pow(&self, exponent: u32) -> Self {
+        BigInt::new(
+            self.negative && exponent % 2 == 1,
+            self.magnitude.pow(exponent),
+        )
+    }
+
+    /// The value, if it fits into an `i128`.
+    pub fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.magnitude.to_u128()?;
+        if self.negative {
+            0_i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+impl From<BigUint> for BigInt {
+    fn from(magnitude: BigUint) -> Self {
+        BigInt::new(false, magnitude)
+    }
+}
+
+impl From<u32> for BigInt {
+    fn from(value: u32) -> Self {
+        BigInt::from(BigUint::from(value))
+    }
+}
+
+impl From<u64> for BigInt {
+    fn from(value: u64) -> Self {
+        BigInt::from(BigUint::from(value))
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(value: i32) -> Self {
+        BigInt::from(i128::from(value))
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(value: i64) -> Self {
+        BigInt::from(i128::from(value))
+    }
+}
+
+impl From<i128> for BigInt {
+    fn from(value: i128) -> Self {
+        BigInt::new(value < 0, BigUint::from(value.unsigned_abs()))
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (negative, _) => other.negative.cmp(&negative),
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude.clone())
+    }
+}
+
+impl Add for &BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: Self) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, &self.magnitude + &other.magnitude);
+        }
+        // The sign of the larger magnitude wins.
+        match self.magnitude.checked_sub(&other.magnitude) {
+            Some(difference) => BigInt::new(self.negative, difference),
+            None => BigInt::new(other.negative, &other.magnitude - &self.magnitude),
+        }
+    }
+}
+
+impl Sub for &BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: Self) -> BigInt {
+        self + &-other
+    }
+}
+
+impl Mul for &BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: Self) -> BigInt {
+        BigInt::new(
+            self.negative != other.negative,
+            &self.magnitude * &other.magnitude,
+        )
+    }
+}
+
+/// Decimal digits with an optional sign.
+impl FromStr for BigInt {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('-') {
+            Some(digits) => Ok(BigInt::new(true, digits.parse()?)),
+            None => Ok(BigInt::from(
+                s.strip_prefix('+').unwrap_or(s).parse::<BigUint>()?,
+            )),
+        }
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad_integral(!self.negative, "", &self.magnitude.to_decimal())
+    }
+}
+
+/// Implements the operators of owned values and the assigning ones by those of
+/// references.
+macro_rules! forward_operators {
+    ($type:ty) => {
+        impl Add for $type {
+            type Output = $type;
+
+            fn add(self, other: Self) -> $type {
+                &self + &other
+            }
+        }
+
+        impl Sub for $type {
+            type Output = $type;
+
+            fn sub(self, other: Self) -> $type {
+                &self - &other
+            }
+        }
+
+        impl Mul for $type {
+            type Output = $type;
+
+            fn mul(self, other: Self) -> $type {
+                &self * &other
+            }
+        }
+
+        impl AddAssign<&$type> for $type {
+            fn add_assign(&mut self, other: &$type) {
+                *self = &*self + other;
+            }
+        }
+
+        impl MulAssign<&$type> for $type {
+            fn mul_assign(&mut self, other: &$type) {
+                *self = &*self * other;
+            }
+        }
+
+        impl Sum for $type {
+            fn sum<I: Iterator<Item = $type>>(iter: I) -> $type {
+                iter.fold(<$type>::from(0_u32), |sum, value| sum + value)
+            }
+        }
+
+        impl Product for $type {
+            fn product<I: Iterator<Item = $type>>(iter: I) -> $type {
+                iter.fold(<$type>::from(1_u32), |product, value| product * value)
+            }
+        }
+    };
+}
+
+forward_operators!(BigUint);
+forward_operators!(BigInt);
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(text: &str) -> BigInt {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays() {
+        for text in [
+            "0",
+            "7",
+            "-42",
+            "4294967296",
+            "123456789012345678901234567890",
+        ] {
+            assert_eq!(big(text).to_string(), text);
+        }
+        assert_eq!(big("+0012").to_string(), "12");
+        assert_eq!(big("-0"), BigInt::zero());
+        assert_eq!(
+            format!("{:>6}|{:<4}|{:+}", big("-12"), big("3"), big("3")),
+            "   -12|3   |+3"
+        );
+        assert!("".parse::<BigUint>().is_err());
+        assert!("-".parse::<BigInt>().is_err());
+        assert!("12a".parse::<BigUint>().is_err());
+        assert!("-5".parse::<BigUint>().is_err());
+    }
+
+    #[test]
+    fn calculates_like_native_integers() {
+        let values = [
+            0,
+            1,
+            -1,
+            7,
+            -13,
+            i64::MAX as i128,
+            i64::MIN as i128,
+            1 << 64,
+            -(1 << 80),
+        ];
+        for a in values {
+            for b in values {
+                let (x, y) = (BigInt::from(a), BigInt::from(b));
+                assert_eq!((&x + &y).to_i128(), Some(a + b));
+                assert_eq!((&x - &y).to_i128(), Some(a - b));
+                assert_eq!(x.cmp(&y), a.cmp(&b));
+                if a.checked_mul(b).is_some() {
+                    assert_eq!((&x * &y).to_i128(), Some(a * b));
+                }
+                if b != 0 {
+                    let (quotient, remainder) = x.div_rem(&y);
+                    assert_eq!(
+                        (quotient.to_i128(), remainder.to_i128()),
+                        (Some(a / b), Some(a % b))
+                    );
+                }
+            }
+        }
+        assert_eq!(BigInt::from(i128::MIN).to_i128(), Some(i128::MIN));
+        assert_eq!(
+            (BigInt::from(i128::MAX) + BigInt::from(1_u32)).to_i128(),
+            None
+        );
+    }
+
+    #[test]
+    fn calculates_beyond_native_integers() {
+        let factorial = (1..=30_u32).map(BigUint::from).product::<BigUint>();
+        assert_eq!(factorial.to_string(), "265252859812191058636308480000000");
+        let (quotient, remainder) = factorial.div_rem(&BigUint::from(1_u128 << 100));
+        assert_eq!(quotient.to_string(), "209");
+        assert_eq!(
+            &(&quotient * &BigUint::from(1_u128 << 100)) + &remainder,
+            factorial
+        );
+
+        let power = BigUint::from(3_u32).pow(200);
+        let divisor = BigUint::from(7_u32).pow(50);
+        let (quotient, remainder) = power.div_rem(&divisor);
+        assert!(remainder < divisor);
+        assert_eq!(&(&quotient * &divisor) + &remainder, power);
+        assert_eq!(&power - &power, BigUint::zero());
+        assert_eq!(
+            BigUint::from(1_u32).checked_sub(&BigUint::from(2_u32)),
+            None
+        );
+
+        let mut sum = BigInt::from(u64::MAX);
+        sum += &BigInt::from(u64::MAX);
+        assert_eq!(sum.to_string(), "36893488147419103230");
+        assert_eq!(
+            (-sum).pow(3).to_string(),
+            "-50216813883093446102519538579558808206140574835118868267000"
+        );
+    }
+}
@@ -0,0 +1,146 @@
+use super::parse::ParseError;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The file with the settings, relative to the working directory. It holds the
+/// session token, so it's never committed.
+pub const CONFIG_FILE: &str = "aoc.conf";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Config {
+    /// The value of the `session` cookie of a logged in user.
+    pub session: Option<String>,
+    /// The server to fetch inputs from, without a trailing `/`. There's no
+    /// default, as `adventofcode.com` is only reachable by HTTPS.
+    pub base_url: Option<String>,
+    /// Where fetched inputs are stored.
+    pub cache_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: None,
+            cache_dir: PathBuf::from("inputs"),
+        }
+    }
+}
+
+impl Config {
+    /// The input of a day in the cache, named the same way for every day.
+    pub fn cached_input(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("{:02}.txt", day))
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> Result<String, String> {
+        Ok(format!("{}/{}/day/{}/input", self.base_url()?, year, day))
+    }
+
+    pub fn answer_url(&self, year: u16, day: u8) -> Result<String, String> {
+        Ok(format!("{}/{}/day/{}/answer", self.base_url()?, year, day))
+    }
+
+    /// The client speaks plain HTTP only, so the server has to be given.
+    fn base_url(&self) -> Result<&str, String> {
+        self.base_url.as_deref().ok_or_else(|| {
+            format!(
+                "No base_url given in {}! HTTPS isn't supported, so point it at a \
+                 TLS-terminating proxy of adventofcode.com or a stand-in server.",
+                CONFIG_FILE
+            )
+        })
+    }
+
+    /// The `Cookie` header identifying the user, which every request needs.
+    pub fn session_cookie(&self) -> Result<String, String> {
+        self.session
+            .as_ref()
+            .map(|session| format!("session={}", session))
+            .ok_or_else(|| format!("No session given in {}!", CONFIG_FILE))
+    }
+}
+
+/// Parses lines of `<key> = <value>`, where missing keys keep their default.
+/// Empty lines and lines starting with `#` are skipped.
+pub fn parse_config(input: &str) -> Result<Config, ParseError> {
+    let mut config = Config::default();
+    for line in input
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+    {
+        let (key, value) = line
+            .split_once('=')
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .ok_or_else(|| ParseError::new(line, "Expected `<key> = <value>`!"))?;
+        match key {
+            "session" => config.session = Some(value.to_string()),
+            "base_url" => config.base_url = Some(value.trim_end_matches('/').to_string()),
+            "cache_dir" => config.cache_dir = Path::new(value).to_path_buf(),
+            _ => return Err(ParseError::new(key, "Unknown key!")),
+        }
+    }
+    Ok(config)
+}
+
+/// Reads the settings, which are all optional, so a missing file means the defaults.
+pub fn load_config() -> Result<Config, String> {
+    match fs::read_to_string(CONFIG_FILE) {
+        Ok(config) => parse_config(&config)
+            .map_err(|err| format!("Couldn't parse {}, {}", CONFIG_FILE, err.locate(&config))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+        Err(err) => Err(format!("Couldn't read {}: {}", CONFIG_FILE, err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_settings() {
+        let config = "# local stand-in\nsession = 53616c74\n\nbase_url = http://localhost:8080/\n";
+        assert_eq!(
+            parse_config(config).unwrap(),
+            Config {
+                session: Some("53616c74".to_string()),
+                base_url: Some("http://localhost:8080".to_string()),
+                cache_dir: PathBuf::from("inputs"),
+            }
+        );
+        assert_eq!(parse_config("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        let config = "session = 1\ntoken = 2";
+        let error = parse_config(config).unwrap_err().locate(config);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(1)));
+        assert!(parse_config("session").is_err());
+    }
+
+    #[test]
+    fn names_inputs_uniformly() {
+        assert!(Config::default().input_url(2020, 7).is_err());
+        let config = Config {
+            base_url: Some("http://localhost:8080".to_string()),
+            ..Config::default()
+        };
+        assert_eq!(
+            config.cached_input(2020, 7),
+            Path::new("inputs").join("2020").join("07.txt")
+        );
+        assert_eq!(
+            config.input_url(2020, 7).unwrap(),
+            "http://localhost:8080/2020/day/7/input"
+        );
+        assert_eq!(
+            config.answer_url(2020, 7).unwrap(),
+            "http://localhost:8080/2020/day/7/answer"
+        );
+        assert!(config.session_cookie().is_err());
+    }
+}
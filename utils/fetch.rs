@@ -0,0 +1,63 @@
+use super::config::Config;
+use super::http;
+use crate::all_days;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub struct FetchArgs {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+}
+
+/// Downloads the input of a day into the cache, which is never done twice.
+/// Returns where the input is and whether it was downloaded.
+pub fn fetch_input(config: &Config, year: u16, day: u8) -> Result<(PathBuf, bool), String> {
+    let path = config.cached_input(year, day);
+    if path.exists() {
+        return Ok((path, false));
+    }
+    let response = http::get(
+        &config.input_url(year, day)?,
+        &[("Cookie", &config.session_cookie()?)],
+    )
+    .map_err(|err| format!("request failed: {}", err))?;
+    if !response.is_success() {
+        return Err(format!(
+            "server answered {} {}",
+            response.status, response.reason
+        ));
+    }
+
+    // Written aside first, so an interrupted download is never taken as cached.
+    let partial = path.with_extension("part");
+    fs::create_dir_all(path.parent().unwrap_or(Path::new(".")))
+        .and_then(|()| fs::write(&partial, response.body))
+        .and_then(|()| fs::rename(&partial, &path))
+        .map_err(|err| format!("couldn't store {}: {}", path.display(), err))?;
+    Ok((path, true))
+}
+
+/// Fetches the inputs of the selected day or of all days of the selected year.
+pub fn fetch(fetch_args: &FetchArgs, config: &Config) -> bool {
+    let days = match (fetch_args.year, fetch_args.day) {
+        (Some(year), Some(day)) => vec![(year, day)],
+        (year, _day) => all_days()
+            .filter(|day| year.is_none_or(|year| year == day.year))
+            .map(|day| (day.year, day.day))
+            .collect(),
+    };
+
+    let mut succeeded = true;
+    for (year, day) in days {
+        match fetch_input(config, year, day) {
+            Ok((path, true)) => println!("{}/{:02}: fetched into {}", year, day, path.display()),
+            Ok((path, false)) => println!("{}/{:02}: cached in {}", year, day, path.display()),
+            Err(err) => {
+                eprintln!("Couldn't fetch {}/{:02}: {}", year, day, err);
+                succeeded = false;
+            }
+        }
+    }
+    succeeded
+}
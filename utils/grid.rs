@@ -0,0 +1,377 @@
+use super::parse::{lines, ParseError};
+use std::ops::{Index, IndexMut};
+
+/// A cell of a grid as `(x, y)`, where `(0, 0)` is the upper left corner.
+pub type Position = (usize, usize);
+
+/// Offsets of the 4 orthogonal neighbors as `(x, y)`, clockwise starting upwards.
+pub const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of all 8 neighbors as `(x, y)`, clockwise starting upwards.
+pub const ADJACENT: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Panics if the number of cells doesn't fit the dimensions.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Cells don't fit the grid!");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, mapping each character to a cell.
+    /// Characters without a mapping and ragged rows are reported as errors.
+    pub fn parse(
+        input: &str,
+        mut mapping: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for line in lines(input) {
+            for (index, character) in line.char_indices() {
+                cells.push(mapping(character).ok_or_else(|| {
+                    ParseError::new(
+                        &line[index..index + character.len_utf8()],
+                        "Unexpected character!",
+                    )
+                })?);
+            }
+            let row_width = cells.len() - height * width.unwrap_or(0);
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(ParseError::new(line, "All rows have to be equally wide!"));
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Position) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position)
+            .map(move |index| &mut self.cells[index])
+    }
+
+    /// Treats the grid as infinitely repeated in every direction.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+        &self.cells[y * self.width + x]
+    }
+
+    /// Moves `position` by `offset`, if the result is still inside the grid.
+    pub fn offset(
+        &self,
+        (x, y): Position,
+        (x_offset, y_offset): (isize, isize),
+    ) -> Option<Position> {
+        let position = (
+            x.checked_add_signed(x_offset)?,
+            y.checked_add_signed(y_offset)?,
+        );
+        Some(position).filter(|position| self.contains(*position))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn map<U>(&self, mut mapping: impl FnMut(Position, &T) -> U) -> Grid<U> {
+        Grid::new(
+            self.width,
+            self.height,
+            self.iter()
+                .map(|(position, cell)| mapping(position, cell))
+                .collect(),
+        )
+    }
+
+    pub fn neighbors_4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |offset| self.offset(position, *offset))
+    }
+
+    pub fn neighbors_8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        ADJACENT
+            .iter()
+            .filter_map(move |offset| self.offset(position, *offset))
+    }
+
+    /// All positions from `start` (exclusive) in `direction` up to the border of the grid.
+    pub fn ray(
+        &self,
+        start: Position,
+        direction: (isize, isize),
+    ) -> impl Iterator<Item = Position> + '_ {
+        std::iter::successors(self.offset(start, direction), move |position| {
+            self.offset(*position, direction)
+        })
+        .take_while(move |_position| direction != (0, 0))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column out of range!");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Rows are read from left to right and columns from top to bottom.
+    pub fn border(&self, side: Side) -> Vec<&T> {
+        match side {
+            Side::Top => self.row(0).iter().collect(),
+            Side::Bottom => self.row(self.height - 1).iter().collect(),
+            Side::Left => self.column(0).collect(),
+            Side::Right => self.column(self.width - 1).collect(),
+        }
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        if self.contains(position) {
+            Some(position.1 * self.width + position.0)
+        } else {
+            None
+        }
+    }
+
+    fn rearranged(&self, width: usize, height: usize, source: impl Fn(Position) -> Position) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..width * height)
+            .map(|index| self[source((index % width, index / width))].clone())
+            .collect();
+        Grid::new(width, height, cells)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn transpose(&self) -> Self {
+        self.rearranged(self.height, self.width, |(x, y)| (y, x))
+    }
+
+    pub fn rotate_clockwise(&self) -> Self {
+        let height = self.height;
+        self.rearranged(self.height, self.width, |(x, y)| (y, height - 1 - x))
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self {
+        let width = self.width;
+        self.rearranged(self.height, self.width, |(x, y)| (width - 1 - y, x))
+    }
+
+    /// Mirrors the grid at its vertical axis, so left and right are swapped.
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        self.rearranged(self.width, self.height, |(x, y)| (width - 1 - x, y))
+    }
+
+    /// Mirrors the grid at its horizontal axis, so top and bottom are swapped.
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        self.rearranged(self.width, self.height, |(x, y)| (x, height - 1 - y))
+    }
+
+    /// All 8 rotations and flips of the grid, starting with the unchanged one.
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut orientations = Vec::with_capacity(8);
+        let mut current = self.clone();
+        for _ in 0..4 {
+            let flipped = current.flip_horizontal();
+            let rotated = current.rotate_clockwise();
+            orientations.push(current);
+            orientations.push(flipped);
+            current = rotated;
+        }
+        orientations
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &Self::Output {
+        self.get(position).expect("Position out of range!")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut Self::Output {
+        self.get_mut(position).expect("Position out of range!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |character| character.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn parses_rows() {
+        let grid = digits("123\n456");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(digits("").width(), 0);
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let input = "12\n3x";
+        let error = Grid::parse(input, |character| character.to_digit(10))
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(2)));
+
+        let input = "12\n345";
+        let error = Grid::parse(input, |character| character.to_digit(10))
+            .unwrap_err()
+            .locate(input);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(1)));
+    }
+
+    #[test]
+    fn checks_bounds() {
+        let grid = digits("12\n34");
+        assert_eq!(grid.get((1, 1)), Some(&4));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.offset((0, 0), (-1, 0)), None);
+        assert_eq!(grid.offset((0, 0), (1, 1)), Some((1, 1)));
+        assert_eq!(*grid.get_wrapping(-1, 5), 4);
+        assert_eq!(*grid.get_wrapping(4, -2), 1);
+    }
+
+    #[test]
+    fn iterates_neighbors() {
+        let grid = digits("123\n456\n789");
+        let neighbors = |positions: Vec<Position>| {
+            positions
+                .into_iter()
+                .map(|position| grid[position])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            neighbors(grid.neighbors_4((1, 1)).collect()),
+            vec![2, 6, 8, 4]
+        );
+        assert_eq!(neighbors(grid.neighbors_4((0, 0)).collect()), vec![2, 4]);
+        assert_eq!(
+            neighbors(grid.neighbors_8((1, 1)).collect()),
+            vec![2, 3, 6, 9, 8, 7, 4, 1]
+        );
+        assert_eq!(neighbors(grid.neighbors_8((2, 2)).collect()), vec![6, 8, 5]);
+    }
+
+    #[test]
+    fn casts_rays() {
+        let grid = digits("123\n456\n789");
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            vec![(1, 1), (2, 2)]
+        );
+        assert_eq!(grid.ray((0, 1), (-1, 0)).count(), 0);
+        assert_eq!(grid.ray((1, 1), (0, 0)).count(), 0);
+    }
+
+    #[test]
+    fn extracts_borders() {
+        let grid = digits("123\n456\n789");
+        let border = |side| grid.border(side).into_iter().copied().collect::<Vec<_>>();
+        assert_eq!(border(Side::Top), vec![1, 2, 3]);
+        assert_eq!(border(Side::Right), vec![3, 6, 9]);
+        assert_eq!(border(Side::Bottom), vec![7, 8, 9]);
+        assert_eq!(border(Side::Left), vec![1, 4, 7]);
+    }
+
+    #[test]
+    fn rotates_and_flips() {
+        let grid = digits("123\n456");
+        assert_eq!(grid.transpose(), digits("14\n25\n36"));
+        assert_eq!(grid.rotate_clockwise(), digits("41\n52\n63"));
+        assert_eq!(grid.rotate_counterclockwise(), digits("36\n25\n14"));
+        assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+        assert_eq!(grid.flip_horizontal(), digits("321\n654"));
+        assert_eq!(grid.flip_vertical(), digits("456\n123"));
+
+        let orientations = digits("12\n34").orientations();
+        assert_eq!(orientations.len(), 8);
+        assert!(orientations
+            .iter()
+            .enumerate()
+            .all(|(index, grid)| !orientations[index + 1..].contains(grid)));
+    }
+
+    #[test]
+    fn maps_cells() {
+        let grid = digits("12\n34").map(|(x, y), value| value * 10 + (x + y) as u32);
+        assert_eq!(
+            grid.values().copied().collect::<Vec<_>>(),
+            vec![10, 21, 31, 42]
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+fn invalid_data(reason: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, reason.into())
+}
+
+/// A plain `http://` URL, HTTPS would need TLS, which isn't part of the std-library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Url {
+    pub host: String,
+    pub port: u16,
+    /// Starts with `/`.
+    pub path: String,
+}
+
+impl Url {
+    pub fn parse(url: &str) -> io::Result<Self> {
+        let rest = url.strip_prefix("http://").ok_or_else(|| {
+            let reason = if url.starts_with("https://") {
+                "HTTPS isn't supported, use a plain HTTP proxy"
+            } else {
+                "Expected an http:// URL"
+            };
+            invalid_data(format!("{}: {}", reason, url))
+        })?;
+        let (authority, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+        // An IPv6 literal is bracketed, as its colons would be taken for the port.
+        let (host, port) = match authority.strip_prefix('[') {
+            Some(bracketed) => match bracketed.split_once(']') {
+                Some((host, "")) => (host, None),
+                Some((host, rest)) => (
+                    host,
+                    Some(rest.strip_prefix(':').ok_or_else(|| {
+                        invalid_data(format!("Expected a port after the host in {}", url))
+                    })?),
+                ),
+                None => return Err(invalid_data(format!("Unclosed bracket in {}", url))),
+            },
+            None => match authority.rsplit_once(':') {
+                Some((host, port)) => (host, Some(port)),
+                None => (authority, None),
+            },
+        };
+        let port = match port {
+            Some(port) => port
+                .parse()
+                .map_err(|_| invalid_data(format!("Invalid port in {}", url)))?,
+            None => 80,
+        };
+        if host.is_empty() {
+            return Err(invalid_data(format!("Missing host in {}", url)));
+        }
+        if host.contains(':') && !authority.starts_with('[') {
+            return Err(invalid_data(format!("Unbracketed IPv6 host in {}", url)));
+        }
+        Ok(Url {
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        })
+    }
+
+    /// The value of the `Host` header, which names the port unless it's the default.
+    pub fn host_header(&self) -> String {
+        let host = if self.host.contains(':') {
+            format!("[{}]", self.host)
+        } else {
+            self.host.clone()
+        };
+        match self.port {
+            80 => host,
+            port => format!("{}:{}", host, port),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub reason: String,
+    pub body: String,
+}
+
+impl Response {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+/// Sends a `GET` request with the extra `headers` and waits for the whole response.
+pub fn get(url: &str, headers: &[(&str, &str)]) -> io::Result<Response> {
+    request("GET", url, headers, None)
+}
+
+/// Sends a `POST` request with a form encoded `body`.
+pub fn post(url: &str, headers: &[(&str, &str)], body: &str) -> io::Result<Response> {
+    request("POST", url, headers, Some(body))
+}
+
+fn request(
+    method: &str,
+    url: &str,
+    headers: &[(&str, &str)],
+    body: Option<&str>,
+) -> io::Result<Response> {
+    let url = Url::parse(url)?;
+    let mut stream = TcpStream::connect((url.host.as_str(), url.port))?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    let mut request = format!(
+        "{} {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: AdventOfCode-rs\r\nConnection: close\r\n",
+        method,
+        url.path,
+        url.host_header()
+    );
+    for (name, value) in headers {
+        request.push_str(&format!("{}: {}\r\n", name, value));
+    }
+    if let Some(body) = body {
+        request.push_str("Content-Type: application/x-www-form-urlencoded\r\n");
+        request.push_str(&format!("Content-Length: {}\r\n", body.len()));
+    }
+    request.push_str("\r\n");
+    request.push_str(body.unwrap_or_default());
+    stream.write_all(request.as_bytes())?;
+
+    read_response(BufReader::new(stream))
+}
+
+/// Escapes a value for a form encoded body, where only unreserved characters stay.
+pub fn form_encode(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            b' ' => "+".to_string(),
+            byte => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+/// Reads a response, whose body is either chunked, of a given length or ends
+/// with the connection.
+pub fn read_response(mut reader: impl BufRead) -> io::Result<Response> {
+    let mut status_line = String::new();
+    reader.read_line(&mut status_line)?;
+    let mut fields = status_line.trim_end().splitn(3, ' ');
+    let (status, reason) = match (fields.next(), fields.next(), fields.next()) {
+        (Some(version), Some(status), reason) if version.starts_with("HTTP/") => (
+            status
+                .parse()
+                .map_err(|_| invalid_data(format!("Invalid status {}", status)))?,
+            reason.unwrap_or_default().to_string(),
+        ),
+        _ => {
+            return Err(invalid_data(format!(
+                "Invalid status line {:?}",
+                status_line
+            )))
+        }
+    };
+
+    let (mut is_chunked, mut length) = (false, None);
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.trim_end().split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("Transfer-Encoding") {
+                is_chunked = value.eq_ignore_ascii_case("chunked");
+            } else if name.eq_ignore_ascii_case("Content-Length") {
+                length = Some(
+                    value
+                        .parse()
+                        .map_err(|_| invalid_data(format!("Invalid length {}", value)))?,
+                );
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+    if is_chunked {
+        loop {
+            let mut size = String::new();
+            reader.read_line(&mut size)?;
+            let size = size.trim_end().split(';').next().unwrap_or_default();
+            let size = usize::from_str_radix(size, 16)
+                .map_err(|_| invalid_data(format!("Invalid chunk size {:?}", size)))?;
+            if size == 0 {
+                break;
+            }
+            let start = body.len();
+            body.resize(start + size, 0);
+            reader.read_exact(&mut body[start..])?;
+            // Every chunk ends with a line break.
+            reader.read_line(&mut String::new())?;
+        }
+    } else if let Some(length) = length {
+        body.resize(length, 0);
+        reader.read_exact(&mut body)?;
+    } else {
+        reader.read_to_end(&mut body)?;
+    }
+
+    Ok(Response {
+        status,
+        reason,
+        body: String::from_utf8(body).map_err(|_| invalid_data("The body isn't UTF-8"))?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::TcpListener;
+    use std::thread;
+
+    #[test]
+    fn parses_urls() {
+        assert_eq!(
+            Url::parse("http://localhost:8080/2020/day/1/input").unwrap(),
+            Url {
+                host: "localhost".to_string(),
+                port: 8080,
+                path: "/2020/day/1/input".to_string(),
+            }
+        );
+        assert_eq!(Url::parse("http://example.com").unwrap().path, "/");
+        assert_eq!(Url::parse("http://example.com").unwrap().port, 80);
+        assert!(Url::parse("https://adventofcode.com").is_err());
+        assert!(Url::parse("http://:80/").is_err());
+    }
+
+    #[test]
+    fn parses_ipv6_hosts() {
+        let url = Url::parse("http://[::1]:8080/input").unwrap();
+        assert_eq!((url.host.as_str(), url.port), ("::1", 8080));
+        assert_eq!(Url::parse("http://[::1]/").unwrap().port, 80);
+        assert!(Url::parse("http://[::1/").is_err());
+        assert!(Url::parse("http://[::1]8080/").is_err());
+        assert!(Url::parse("http://::1/").is_err());
+    }
+
+    #[test]
+    fn names_the_port_in_the_host_header() {
+        let host = |url| Url::parse(url).unwrap().host_header();
+        assert_eq!(host("http://example.com/"), "example.com");
+        assert_eq!(host("http://localhost:8080/"), "localhost:8080");
+        assert_eq!(host("http://[::1]/"), "[::1]");
+        assert_eq!(host("http://[::1]:8080/"), "[::1]:8080");
+    }
+
+    #[test]
+    fn encodes_forms() {
+        assert_eq!(form_encode("-123"), "-123");
+        assert_eq!(
+            form_encode("mxmx,sqjhc fvjkl&ä"),
+            "mxmx%2Csqjhc+fvjkl%26%C3%A4"
+        );
+    }
+
+    #[test]
+    fn reads_responses() {
+        let response = "HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\n1\n2\n\nignored";
+        assert_eq!(
+            read_response(response.as_bytes()).unwrap(),
+            Response {
+                status: 200,
+                reason: "OK".to_string(),
+                body: "1\n2\n\n".to_string(),
+            }
+        );
+
+        let response = "HTTP/1.1 404 Not Found\r\nTransfer-Encoding: chunked\r\n\r\n\
+                        4\r\nabcd\r\n3;x=y\r\nefg\r\n0\r\n\r\n";
+        let response = read_response(response.as_bytes()).unwrap();
+        assert_eq!((response.status, response.body.as_str()), (404, "abcdefg"));
+        assert!(!response.is_success());
+
+        assert!(read_response("garbage\r\n\r\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn talks_to_a_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = thread::spawn(move || {
+            let (stream, _address) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            while reader.read_line(&mut request).unwrap() > 2 {}
+            let mut body = vec![0; 17];
+            reader.read_exact(&mut body).unwrap();
+            request.push_str(&String::from_utf8(body).unwrap());
+            reader
+                .get_mut()
+                .write_all(b"HTTP/1.1 200 OK\r\n\r\n1\n2\n")
+                .unwrap();
+            request
+        });
+
+        let url = format!("http://127.0.0.1:{}/2020/day/1/answer", port);
+        let response = post(&url, &[("Cookie", "session=abc")], "level=1&answer=42").unwrap();
+        assert_eq!(response.body, "1\n2\n");
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2020/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc\r\n"));
+        assert!(request.contains("\r\nContent-Length: 17\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=1&answer=42"));
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle input of a day is read from at runtime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    /// The data file shipped with the day, relative to the working directory.
+    Bundled(PathBuf),
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Interprets a command-line argument, where `-` stands for stdin.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => InputSource::Stdin,
+            path => InputSource::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::Bundled(path) => read_file(path),
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Bundled(path) | InputSource::File(path) => {
+                write!(f, "{}", path.display())
+            }
+            InputSource::Stdin => write!(f, "-"),
+        }
+    }
+}
+
+fn read_file(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path.display(), err)))
+}
@@ -0,0 +1,236 @@
+use super::bigint::BigInt;
+use std::fmt::{self, Display, Write};
+
+/// A JSON value, which is written compactly by `Display` or indented by `pretty`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Integer(BigInt),
+    /// Non-finite numbers aren't representable and are written as `null`.
+    Float(f64),
+    String(String),
+    Array(Vec<Json>),
+    /// Keys are written in the given order.
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    pub fn object<K: Into<String>>(fields: impl IntoIterator<Item = (K, Json)>) -> Self {
+        Json::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
+    }
+
+    /// Writes nested arrays and objects on their own lines, indented by two spaces.
+    pub fn pretty(&self) -> String {
+        let mut output = String::new();
+        self.write_pretty(&mut output, 0)
+            .expect("Writing to a string failed!");
+        output
+    }
+
+    fn write_pretty(&self, output: &mut String, depth: usize) -> fmt::Result {
+        let indent = |depth: usize| "  ".repeat(depth);
+        match self {
+            Json::Array(values) if !values.is_empty() => {
+                output.push_str("[\n");
+                for (index, value) in values.iter().enumerate() {
+                    output.push_str(&indent(depth + 1));
+                    value.write_pretty(output, depth + 1)?;
+                    output.push_str(if index + 1 < values.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                write!(output, "{}]", indent(depth))
+            }
+            Json::Object(fields) if !fields.is_empty() => {
+                output.push_str("{\n");
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    write!(output, "{}", indent(depth + 1))?;
+                    write_string(output, key)?;
+                    output.push_str(": ");
+                    value.write_pretty(output, depth + 1)?;
+                    output.push_str(if index + 1 < fields.len() {
+                        ",\n"
+                    } else {
+                        "\n"
+                    });
+                }
+                write!(output, "{}}}", indent(depth))
+            }
+            value => write!(output, "{}", value),
+        }
+    }
+}
+
+fn write_string(f: &mut impl Write, text: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for character in text.chars() {
+        match character {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            character if character.is_control() => write!(f, "\\u{:04x}", character as u32)?,
+            character => f.write_char(character)?,
+        }
+    }
+    f.write_char('"')
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(value) => write!(f, "{}", value),
+            Json::Integer(value) => write!(f, "{}", value),
+            Json::Float(value) if value.is_finite() => write!(f, "{}", value),
+            Json::Float(_value) => write!(f, "null"),
+            Json::String(text) => write_string(f, text),
+            Json::Array(values) => {
+                write!(f, "[")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (index, (key, value)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<i64> for Json {
+    fn from(value: i64) -> Self {
+        Json::Integer(value.into())
+    }
+}
+
+impl From<u64> for Json {
+    fn from(value: u64) -> Self {
+        Json::Integer(value.into())
+    }
+}
+
+impl From<i128> for Json {
+    fn from(value: i128) -> Self {
+        Json::Integer(value.into())
+    }
+}
+
+impl From<BigInt> for Json {
+    fn from(value: BigInt) -> Self {
+        Json::Integer(value)
+    }
+}
+
+impl From<f64> for Json {
+    fn from(value: f64) -> Self {
+        Json::Float(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(text: &str) -> Self {
+        Json::String(text.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(text: String) -> Self {
+        Json::String(text)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+impl<T: Into<Json>> From<Vec<T>> for Json {
+    fn from(values: Vec<T>) -> Self {
+        Json::Array(values.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_values() {
+        assert_eq!(Json::Null.to_string(), "null");
+        assert_eq!(Json::from(true).to_string(), "true");
+        assert_eq!(Json::from(-12_i64).to_string(), "-12");
+        assert_eq!(Json::from(u64::MAX).to_string(), "18446744073709551615");
+        let big = "-123456789012345678901234567890123456789012";
+        assert_eq!(Json::from(big.parse::<BigInt>().unwrap()).to_string(), big);
+        assert_eq!(Json::from(0.5).to_string(), "0.5");
+        assert_eq!(Json::from(f64::NAN).to_string(), "null");
+        assert_eq!(Json::from(None::<i64>).to_string(), "null");
+        assert_eq!(Json::from(vec![1_i64, 2]).to_string(), "[1,2]");
+    }
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(
+            Json::from("a \"b\"\\\n\u{1}ä").to_string(),
+            "\"a \\\"b\\\"\\\\\\n\\u0001ä\""
+        );
+    }
+
+    #[test]
+    fn writes_objects() {
+        let object = Json::object([
+            ("day", Json::from(16_i64)),
+            ("parts", Json::Array(Vec::new())),
+            ("input", Json::from("16_data.txt")),
+        ]);
+        assert_eq!(
+            object.to_string(),
+            "{\"day\":16,\"parts\":[],\"input\":\"16_data.txt\"}"
+        );
+        assert_eq!(
+            object.pretty(),
+            "{\n  \"day\": 16,\n  \"parts\": [],\n  \"input\": \"16_data.txt\"\n}"
+        );
+    }
+
+    #[test]
+    fn indents_nested_values() {
+        let array = Json::Array(vec![
+            Json::object([("a", Json::from(vec![1_i64]))]),
+            Json::Null,
+        ]);
+        assert_eq!(
+            array.pretty(),
+            "[\n  {\n    \"a\": [\n      1\n    ]\n  },\n  null\n]"
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use super::answer::Answer;
+use super::input::InputSource;
+use super::parse::{parse_value, ParseError};
+use super::runner::{run_day, Output, RunArgs};
+use super::solution::Part;
+use crate::all_days;
+use std::path::PathBuf;
+
+/// The file with the known answers, relative to the working directory.
+pub const ANSWERS_FILE: &str = "answers.txt";
+
+/// A known answer of a part for one input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub input: PathBuf,
+    pub answer: String,
+}
+
+/// Parses lines of `<year> <day> <part> <input> <answer>`, where the answer is the
+/// rest of the line. Empty lines and lines starting with `#` are skipped.
+pub fn parse_manifest(input: &str) -> Result<Vec<Expected>, ParseError> {
+    input
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.splitn(5, ' ');
+            let mut next_field = || {
+                fields
+                    .next()
+                    .filter(|field| !field.is_empty())
+                    .ok_or_else(|| {
+                        ParseError::new(line, "Expected `<year> <day> <part> <input> <answer>`!")
+                    })
+            };
+
+            let year = parse_value(next_field()?)?;
+            let day = parse_value(next_field()?)?;
+            let part_field = next_field()?;
+            let part = parse_value(part_field)
+                .ok()
+                .and_then(Part::from_number)
+                .ok_or_else(|| ParseError::new(part_field, "Unknown part!"))?;
+            let input = PathBuf::from(next_field()?);
+            let answer = next_field()?.to_string();
+            Ok(Expected {
+                year,
+                day,
+                part,
+                input,
+                answer,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct VerifyArgs {
+    pub run_args: RunArgs,
+    pub answers: PathBuf,
+}
+
+/// Solves the part of a known answer on its input.
+pub fn solve_expected(expected: &Expected) -> Result<Answer, String> {
+    let day = all_days()
+        .find(|day| (day.year, day.day) == (expected.year, expected.day))
+        .ok_or("day isn't solved")?;
+    let solved = run_day(
+        day,
+        InputSource::File(expected.input.clone()),
+        &[expected.part],
+    )
+    .solved?;
+    match solved.answers.into_iter().next() {
+        Some((_part, Answer::Failed(reason), _cost)) => Err(reason),
+        Some((_part, answer, _cost)) => Ok(answer),
+        None => panic!("No answer!"),
+    }
+}
+
+/// Checks the selected days against the known answers, returns `false` on any mismatch.
+pub fn verify(verify_args: &VerifyArgs) -> bool {
+    let manifest = match InputSource::File(verify_args.answers.clone()).read() {
+        Ok(manifest) => manifest,
+        Err(err) => {
+            eprintln!("Couldn't read answers: {}", err);
+            return false;
+        }
+    };
+    let expected_answers = match parse_manifest(&manifest) {
+        Ok(expected_answers) => expected_answers,
+        Err(err) => {
+            eprintln!("Couldn't parse answers, {}", err.locate(&manifest));
+            return false;
+        }
+    };
+
+    let run_args = &verify_args.run_args;
+    let (mut passed, mut failed) = (0, 0);
+    for expected in expected_answers.iter().filter(|expected| {
+        run_args.year.is_none_or(|year| year == expected.year)
+            && run_args.day.is_none_or(|day| day == expected.day)
+            && run_args.part.is_none_or(|part| part == expected.part)
+    }) {
+        let label = format!(
+            "{}/{:02} part {} ({})",
+            expected.year,
+            expected.day,
+            expected.part.number(),
+            expected.input.display()
+        );
+        match solve_expected(expected) {
+            Ok(answer) if answer.to_string() == expected.answer => {
+                if run_args.output != Output::Quiet {
+                    println!("{}: pass", label);
+                }
+                passed += 1;
+            }
+            Ok(answer) => {
+                println!(
+                    "{}: FAIL\n  expected: {}\n  actual:   {}",
+                    label, expected.answer, answer
+                );
+                failed += 1;
+            }
+            Err(err) => {
+                println!("{}: FAIL, {}", label, err);
+                failed += 1;
+            }
+        }
+    }
+    println!("{} passed, {} failed", passed, failed);
+    failed == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answers() {
+        let manifest = "# comment\n\n2020 07 2 2020/07_data.rules 1250\n2020 21 1 2020/21_data.txt mxmx, sqjhc\n";
+        assert_eq!(
+            parse_manifest(manifest).unwrap(),
+            vec![
+                Expected {
+                    year: 2020,
+                    day: 7,
+                    part: Part::Two,
+                    input: PathBuf::from("2020/07_data.rules"),
+                    answer: "1250".to_string(),
+                },
+                Expected {
+                    year: 2020,
+                    day: 21,
+                    part: Part::One,
+                    input: PathBuf::from("2020/21_data.txt"),
+                    answer: "mxmx, sqjhc".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_lines() {
+        let manifest = "2020 01 1 a.list 1\n2020 01 3 a.list 2\n2020 02 1 b.list";
+        let error = parse_manifest(manifest).unwrap_err().locate(manifest);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(9)));
+
+        let manifest = "2020 02 1 b.list";
+        let error = parse_manifest(manifest).unwrap_err().locate(manifest);
+        assert_eq!((error.line(), error.column()), (Some(1), Some(1)));
+    }
+}
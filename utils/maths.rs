@@ -1,4 +1,11 @@
-pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+use super::random::Rng;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::ops::{Add, Div, Mul, MulAssign, Neg, Range, Sub};
+
+/// The extended Euclidean algorithm: `(g, x, y)` with `a * x + b * y = g`, where
+/// `g` is the greatest common divisor of `a` and `b`.
+pub fn egcd(a: i128, b: i128) -> (i128, i128, i128) {
     if a == 0 {
         (b, 0, 1)
     } else {
@@ -7,21 +14,764 @@ pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
     }
 }
 
-pub fn mod_inverse(x: i64, n: i64) -> i64 {
-    let (_, x, _) = egcd(x, n);
-    (x % n + n) % n
+/// The inverse of `x` modulo `n`, which only exists if both are coprime.
+pub fn mod_inverse(x: i128, n: i128) -> Option<i128> {
+    match egcd(x.rem_euclid(n), n) {
+        (1, x, _) => Some(x.rem_euclid(n)),
+        _ => None,
+    }
+}
+
+/// `a * b % modulus` for `0 <= a, b < modulus`, which falls back to adding up
+/// the doubled `a`s if the product overflows.
+fn mul_mod(a: i128, b: i128, modulus: i128) -> i128 {
+    if let Some(product) = a.checked_mul(b) {
+        return product % modulus;
+    }
+    // Sums of two numbers below `modulus` fit into an `u128`.
+    let (mut a, mut b, modulus) = (a as u128, b as u128, modulus as u128);
+    let mut product = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            product = (product + a) % modulus;
+        }
+        a = (a + a) % modulus;
+        b >>= 1;
+    }
+    product as i128
+}
+
+/// Why a system of congruences can't be solved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CongruenceError {
+    /// Two congruences contradict each other, e.g. `x ≡ 0 (mod 2)` and `x ≡ 1 (mod 4)`.
+    Inconsistent,
+    /// The combined modulus doesn't fit into an `i128`.
+    Overflow,
+    /// A modulus has to be positive.
+    InvalidModulus(i128),
 }
 
-/// http://rosettacode.org/wiki/Chinese_remainder_theorem#Rust
-pub fn chinese_remainder(pairs: &[(i64, i64)]) -> i64 {
-    let product = pairs.iter().map(|(r, _)| r).product::<i64>();
+impl Display for CongruenceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CongruenceError::Inconsistent => write!(f, "The congruences are inconsistent!"),
+            CongruenceError::Overflow => write!(f, "The combined modulus overflows!"),
+            CongruenceError::InvalidModulus(modulus) => {
+                write!(f, "The modulus {} isn't positive!", modulus)
+            }
+        }
+    }
+}
 
-    pairs
+/// Solves the system of congruences `x ≡ residue (mod modulus)`, given as
+/// `(modulus, residue)` pairs, whose moduli don't need to be coprime.
+///
+/// The congruences are merged pairwise, so the solution is `x ≡ residue (mod modulus)`
+/// again, where `modulus` is the least common multiple of all moduli and
+/// `0 <= residue < modulus`. Without any congruence, that's `(0, 1)`.
+pub fn chinese_remainder(congruences: &[(i128, i128)]) -> Result<(i128, i128), CongruenceError> {
+    congruences
         .iter()
-        .map(|(i, j)| {
-            let p = product / i;
-            j * mod_inverse(p, *i) * p
+        .try_fold(
+            (1, 0),
+            |(modulus, residue), &(other_modulus, other_residue)| {
+                if other_modulus <= 0 {
+                    return Err(CongruenceError::InvalidModulus(other_modulus));
+                }
+                let other_residue = other_residue.rem_euclid(other_modulus);
+                // modulus * p + other_modulus * q = g
+                let (g, p, _q) = egcd(modulus, other_modulus);
+                let difference = other_residue - residue;
+                if difference % g != 0 {
+                    return Err(CongruenceError::Inconsistent);
+                }
+                // x = residue + modulus * t, where modulus * t ≡ difference (mod other_modulus).
+                let step = other_modulus / g;
+                let t = mul_mod((difference / g).rem_euclid(step), p.rem_euclid(step), step);
+                let combined = modulus.checked_mul(step).ok_or(CongruenceError::Overflow)?;
+                // Below `combined`, since residue < modulus and t < step.
+                Ok((combined, residue + modulus * t))
+            },
+        )
+        .map(|(modulus, residue)| (residue, modulus))
+}
+
+/// A residue modulo a positive `modulus`, e.g. `ModInt::new(5, 7) * ModInt::new(3, 7)`
+/// is `1 (mod 7)`. Intermediate results are widened to `u128`, so any `u64`
+/// modulus works, and mixing different moduli panics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ModInt {
+    value: u64,
+    modulus: u64,
+}
+
+impl ModInt {
+    pub fn new(value: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "The modulus has to be positive!");
+        ModInt {
+            value: value % modulus,
+            modulus,
+        }
+    }
+
+    /// The residue of a negative value, too.
+    pub fn from_signed(value: i128, modulus: u64) -> Self {
+        ModInt::new(value.rem_euclid(modulus.into()) as u64, modulus)
+    }
+
+    /// The representative in `0..modulus`.
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn modulus(self) -> u64 {
+        self.modulus
+    }
+
+    /// Exponentiation by squaring, where `x^0` is `1`.
+    pub fn pow(self, mut exponent: u64) -> Self {
+        let mut base = self;
+        let mut result = ModInt::new(1, self.modulus);
+        while exponent != 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            exponent >>= 1;
+            base *= base;
+        }
+        result
+    }
+
+    /// The residue, which multiplied with this one is `1`, if both are coprime.
+    pub fn inverse(self) -> Option<Self> {
+        mod_inverse(self.value.into(), self.modulus.into())
+            .map(|inverse| ModInt::new(inverse as u64, self.modulus))
+    }
+
+    fn with(self, other: Self, operation: impl FnOnce(u128, u128, u128) -> u128) -> Self {
+        assert_eq!(self.modulus, other.modulus, "Different moduli!");
+        let modulus = self.modulus as u128;
+        let value = operation(self.value.into(), other.value.into(), modulus) % modulus;
+        ModInt::new(value as u64, self.modulus)
+    }
+}
+
+impl Add for ModInt {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.with(other, |a, b, _modulus| a + b)
+    }
+}
+
+impl Sub for ModInt {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.with(other, |a, b, modulus| a + modulus - b)
+    }
+}
+
+impl Mul for ModInt {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.with(other, |a, b, _modulus| a * b)
+    }
+}
+
+impl MulAssign for ModInt {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Neg for ModInt {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        ModInt::new(0, self.modulus) - self
+    }
+}
+
+/// Multiplies with the inverse, so it panics if the divisor has none.
+impl Div for ModInt {
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, other: Self) -> Self {
+        self * other.inverse().expect("The divisor isn't invertible!")
+    }
+}
+
+impl Display for ModInt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value, self.modulus)
+    }
+}
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, unless it overflows.
+pub fn lcm(a: u64, b: u64) -> Option<u64> {
+    match gcd(a, b) {
+        0 => Some(0),
+        divisor => (a / divisor).checked_mul(b),
+    }
+}
+
+/// The greatest common divisor of all numbers, where that of none is `0`.
+pub fn gcd_all(numbers: &[u64]) -> u64 {
+    numbers
+        .iter()
+        .fold(0, |divisor, number| gcd(divisor, *number))
+}
+
+/// The least common multiple of all numbers, where that of none is `1`.
+pub fn lcm_all(numbers: &[u64]) -> Option<u64> {
+    numbers
+        .iter()
+        .try_fold(1, |multiple, number| lcm(multiple, *number))
+}
+
+/// A deterministic Miller-Rabin test, since these bases leave no strong
+/// pseudoprime below 2^64.
+pub fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false;
+    }
+    if let Some(base) = BASES.iter().find(|base| n.is_multiple_of(**base)) {
+        return n == *base;
+    }
+    // n - 1 = odd * 2^twos
+    let twos = (n - 1).trailing_zeros();
+    let odd = (n - 1) >> twos;
+    let minus_one = ModInt::new(n - 1, n);
+    BASES.iter().all(|base| {
+        let mut x = ModInt::new(*base, n).pow(odd);
+        if x.value() == 1 || x == minus_one {
+            return true;
+        }
+        (1..twos).any(|_| {
+            x *= x;
+            x == minus_one
         })
-        .sum::<i64>()
-        % product
+    })
+}
+
+/// A non-trivial divisor of an odd composite number by Pollard's rho algorithm,
+/// which retries with another polynomial if the cycle doesn't reveal one.
+fn pollard_rho(n: u64) -> u64 {
+    (1..)
+        .find_map(|constant| {
+            let constant = ModInt::new(constant, n);
+            let step = |x: ModInt| x * x + constant;
+            let (mut tortoise, mut hare) = (ModInt::new(2, n), ModInt::new(2, n));
+            let mut divisor = 1;
+            while divisor == 1 {
+                tortoise = step(tortoise);
+                hare = step(step(hare));
+                divisor = gcd(tortoise.value().abs_diff(hare.value()), n);
+            }
+            (divisor != n).then_some(divisor)
+        })
+        .expect("No divisor found!")
+}
+
+/// The prime factors of a positive number with their exponents in ascending order,
+/// e.g. `[(2, 2), (3, 1)]` for 12.
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    assert!(n > 0, "Zero has no prime factorization!");
+    let twos = n.trailing_zeros();
+    let mut primes = vec![2; twos as usize];
+    let mut composites = vec![n >> twos];
+    while let Some(number) = composites.pop() {
+        if number == 1 {
+            continue;
+        }
+        if is_prime(number) {
+            primes.push(number);
+        } else {
+            let divisor = pollard_rho(number);
+            composites.extend([divisor, number / divisor]);
+        }
+    }
+    primes.sort_unstable();
+
+    let mut factors: Vec<(u64, u32)> = Vec::new();
+    for prime in primes {
+        match factors.last_mut() {
+            Some((last, exponent)) if *last == prime => *exponent += 1,
+            _ => factors.push((prime, 1)),
+        }
+    }
+    factors
+}
+
+/// The primes in `range`, which are sieved in segments as long as the square
+/// root of its end, so that large numbers need little memory.
+pub fn primes_in(range: Range<u64>) -> Vec<u64> {
+    let root = range.end.isqrt();
+    let mut small_composites = vec![false; root as usize + 1];
+    let mut small_primes = Vec::new();
+    for number in 2..=root {
+        if !small_composites[number as usize] {
+            small_primes.push(number);
+            for multiple in (number * number..=root).step_by(number as usize) {
+                small_composites[multiple as usize] = true;
+            }
+        }
+    }
+
+    let segment_size = root.max(1 << 15);
+    let mut primes = Vec::new();
+    let mut low = range.start.max(2);
+    while low < range.end {
+        let high = range.end.min(low.saturating_add(segment_size));
+        let mut composites = vec![false; (high - low) as usize];
+        for prime in &small_primes {
+            let first = (prime * prime).max(low.div_ceil(*prime) * prime);
+            for multiple in (first..high).step_by(*prime as usize) {
+                composites[(multiple - low) as usize] = true;
+            }
+        }
+        primes.extend((low..high).filter(|number| !composites[(number - low) as usize]));
+        low = high;
+    }
+    primes
+}
+
+/// Euler's totient function, which counts the numbers up to `n` coprime to it.
+pub fn totient(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .fold(n, |totient, (prime, _exponent)| {
+            totient / prime * (prime - 1)
+        })
+}
+
+/// The Carmichael function, which is the smallest exponent `m` with `a^m ≡ 1 (mod n)`
+/// for every `a` coprime to `n`.
+pub fn carmichael(n: u64) -> u64 {
+    factorize(n)
+        .into_iter()
+        .map(|(prime, exponent)| match (prime, exponent) {
+            (2, 3..) => 1 << (exponent - 2),
+            _ => prime.pow(exponent - 1) * (prime - 1),
+        })
+        .fold(1, |multiple, order| {
+            lcm(multiple, order).expect("Below the totient!")
+        })
+}
+
+/// The smallest positive exponent `m` with `a^m ≡ 1 (mod n)`, which only exists
+/// if `a` and `n` are coprime.
+pub fn multiplicative_order(a: u64, n: u64) -> Option<u64> {
+    if n == 0 || gcd(a, n) != 1 {
+        return None;
+    }
+    // The order divides the Carmichael function, so its prime factors are dropped
+    // while the power stays 1.
+    let a = ModInt::new(a, n);
+    let mut order = carmichael(n);
+    for (prime, _exponent) in factorize(order) {
+        while order.is_multiple_of(prime) && a.pow(order / prime).value() == 1 % n {
+            order /= prime;
+        }
+    }
+    Some(order)
+}
+
+/// The smallest exponent `x` below `order` with `base^x = target` by the baby-step
+/// giant-step algorithm, where `order` is at least the order of the invertible `base`,
+/// e.g. the modulus. It needs a table of `sqrt(order)` powers, which are multiplied
+/// up one after another like the giant steps.
+pub fn baby_step_giant_step(base: ModInt, target: ModInt, order: u64) -> Option<u64> {
+    let steps = order.isqrt() + 1;
+    let mut baby_steps = HashMap::new();
+    let mut power = ModInt::new(1, base.modulus());
+    for exponent in 0..steps {
+        baby_steps.entry(power).or_insert(exponent);
+        power *= base;
+    }
+    let giant_step = power.inverse()?;
+    let mut value = target;
+    for giant_steps in 0..steps {
+        if let Some(exponent) = baby_steps.get(&value) {
+            return Some(giant_steps * steps + exponent).filter(|exponent| *exponent < order);
+        }
+        value *= giant_step;
+    }
+    None
+}
+
+/// The exponent `x` with `base^x = target` by Pollard's rho algorithm, where `order`
+/// is the prime order of `base`, so the exponent is unique below it. It only needs
+/// constant memory, but as a random walk it may rarely give up.
+pub fn pollard_rho_logarithm(base: ModInt, target: ModInt, order: u64) -> Option<u64> {
+    const ATTEMPTS: usize = 16;
+    if target == base.pow(0) {
+        return Some(0);
+    }
+    // Every value of the walk is base^a * target^b, with the exponents modulo the order.
+    let step = |(value, a, b): (ModInt, ModInt, ModInt)| match value.value() % 3 {
+        0 => (value * value, a + a, b + b),
+        1 => (value * base, a + ModInt::new(1, order), b),
+        _ => (value * target, a, b + ModInt::new(1, order)),
+    };
+    let mut rng = Rng::new(order);
+    (0..ATTEMPTS).find_map(|_attempt| {
+        let a = ModInt::new(rng.below(order), order);
+        let b = ModInt::new(rng.below(order), order);
+        let start = (base.pow(a.value()) * target.pow(b.value()), a, b);
+        let (mut tortoise, mut hare) = (step(start), step(step(start)));
+        while tortoise.0 != hare.0 {
+            tortoise = step(tortoise);
+            hare = step(step(hare));
+        }
+        // base^a1 * target^b1 = base^a2 * target^b2, so x * (b1 - b2) = a2 - a1.
+        let ((_, a1, b1), (_, a2, b2)) = (tortoise, hare);
+        if b1 == b2 {
+            return None;
+        }
+        let exponent = ((a2 - a1) / (b1 - b2)).value();
+        (base.pow(exponent) == target).then_some(exponent)
+    })
+}
+
+/// The logarithm in a group of prime order, by whichever algorithm suits its size.
+fn prime_order_logarithm(base: ModInt, target: ModInt, order: u64) -> Option<u64> {
+    // Up to 2^18 baby steps are cheaper than the walks.
+    if order < 1 << 36 {
+        baby_step_giant_step(base, target, order)
+    } else {
+        pollard_rho_logarithm(base, target, order)
+    }
+}
+
+/// The Pohlig-Hellman algorithm for an invertible `base`: the exponent modulo each
+/// prime power of its order is found digit by digit in a group of prime order, and
+/// combined by the Chinese remainder theorem. Returns the exponent below the order
+/// together with the order.
+fn pohlig_hellman(base: ModInt, target: ModInt) -> Option<(u64, u64)> {
+    let order = multiplicative_order(base.value(), base.modulus())?;
+    let inverse = base.inverse()?;
+    let mut congruences = Vec::new();
+    for (prime, exponent) in factorize(order) {
+        let generator = base.pow(order / prime);
+        let mut residue = 0;
+        let mut prime_power = 1;
+        for _digit in 0..exponent {
+            let reduced = (target * inverse.pow(residue)).pow(order / prime_power / prime);
+            residue += prime_order_logarithm(generator, reduced, prime)? * prime_power;
+            prime_power *= prime;
+        }
+        congruences.push((prime_power.into(), residue.into()));
+    }
+    let (exponent, _order) = chinese_remainder(&congruences).ok()?;
+    let exponent = exponent as u64;
+    (base.pow(exponent) == target).then_some((exponent, order))
+}
+
+/// The smallest exponent `x` with `base^x ≡ target (mod modulus)` for any positive
+/// modulus, if there's one.
+///
+/// Powers of a base sharing factors with the modulus are only periodic from an
+/// exponent of at most 63 on, so smaller exponents are tried first. Beyond them,
+/// they're divisible by the shared prime powers of the modulus, so only the
+/// remaining coprime part is solved by [`pohlig_hellman`].
+pub fn discrete_logarithm(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    const PERIODIC: u64 = 64;
+    let (base, target) = (ModInt::new(base, modulus), ModInt::new(target, modulus));
+    let mut power = ModInt::new(1, modulus);
+    for exponent in 0..PERIODIC {
+        if power == target {
+            return Some(exponent);
+        }
+        power *= base;
+    }
+
+    let mut coprime_modulus = modulus;
+    while gcd(coprime_modulus, base.value()) != 1 {
+        coprime_modulus /= gcd(coprime_modulus, base.value());
+    }
+    if target.value() % (modulus / coprime_modulus) != 0 {
+        return None;
+    }
+    let (exponent, order) = pohlig_hellman(
+        ModInt::new(base.value(), coprime_modulus),
+        ModInt::new(target.value(), coprime_modulus),
+    )?;
+    // The smallest exponent from the periodic part on.
+    Some(exponent + PERIODIC.saturating_sub(exponent).div_ceil(order) * order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inverts_coprime_numbers() {
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(1, 1), Some(0));
+        assert_eq!(mod_inverse(4, 6), None);
+    }
+
+    #[test]
+    fn solves_coprime_moduli() {
+        assert_eq!(chinese_remainder(&[(3, 2), (5, 3), (7, 2)]), Ok((23, 105)));
+        assert_eq!(chinese_remainder(&[(7, -1), (13, 12)]), Ok((90, 91)));
+        assert_eq!(chinese_remainder(&[]), Ok((0, 1)));
+    }
+
+    #[test]
+    fn solves_non_coprime_moduli() {
+        assert_eq!(chinese_remainder(&[(4, 3), (6, 5)]), Ok((11, 12)));
+        assert_eq!(
+            chinese_remainder(&[(6, 2), (10, 4), (15, 14)]),
+            Ok((14, 30))
+        );
+        assert_eq!(chinese_remainder(&[(4, 1), (4, 5)]), Ok((1, 4)));
+    }
+
+    #[test]
+    fn solves_large_moduli() {
+        let (first, second) = ((1 << 100) + 277, (1 << 26) - 5);
+        let (residue, modulus) = chinese_remainder(&[(first, 12345), (second, 678)]).unwrap();
+        assert_eq!(modulus, first * second);
+        assert_eq!((residue % first, residue % second), (12345, 678));
+    }
+
+    #[test]
+    fn detects_unsolvable_systems() {
+        assert_eq!(
+            chinese_remainder(&[(2, 0), (4, 1)]),
+            Err(CongruenceError::Inconsistent)
+        );
+        assert_eq!(
+            chinese_remainder(&[(3, 1), (0, 1)]),
+            Err(CongruenceError::InvalidModulus(0))
+        );
+        let large_primes = [(1 << 61) - 1, (1 << 31) - 1, (1 << 89) - 1];
+        assert_eq!(
+            chinese_remainder(&large_primes.map(|prime| (prime, 1))),
+            Err(CongruenceError::Overflow)
+        );
+        assert_eq!(
+            chinese_remainder(
+                &large_primes[..2]
+                    .iter()
+                    .map(|prime| (*prime, 1))
+                    .collect::<Vec<_>>()
+            ),
+            Ok((1, ((1 << 61) - 1) * ((1 << 31) - 1)))
+        );
+    }
+
+    #[test]
+    fn calculates_modulo() {
+        let residue = |value| ModInt::new(value, 7);
+        assert_eq!(residue(5) + residue(4), residue(2));
+        assert_eq!(residue(2) - residue(5), residue(4));
+        assert_eq!(residue(5) * residue(3), residue(1));
+        assert_eq!(-residue(3), residue(4));
+        assert_eq!(-residue(0), residue(0));
+        assert_eq!(residue(1) / residue(3), residue(5));
+        assert_eq!(residue(3).pow(6), residue(1));
+        assert_eq!(residue(3).pow(0), residue(1));
+        assert_eq!(ModInt::from_signed(-10, 7), residue(4));
+        assert_eq!(residue(10).to_string(), "3 (mod 7)");
+    }
+
+    #[test]
+    fn multiplies_large_moduli() {
+        let modulus = u64::MAX - 58; // The largest prime below 2^64.
+        let large = ModInt::new(u64::MAX - 59, modulus);
+        assert_eq!((large * large).value(), 1);
+        assert_eq!((large + large).value(), modulus - 2);
+        assert_eq!(large.pow(modulus - 1).value(), 1);
+        assert_eq!(large / large, ModInt::new(1, modulus));
+        assert_eq!(ModInt::new(2, 6).inverse(), None);
+    }
+
+    #[test]
+    fn divides_slices() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd_all(&[12, 18, 8]), 2);
+        assert_eq!(gcd_all(&[]), 0);
+        assert_eq!(lcm_all(&[4, 6, 10]), Some(60));
+        assert_eq!(lcm_all(&[]), Some(1));
+        assert_eq!(lcm_all(&[3, 0]), Some(0));
+        assert_eq!(lcm_all(&[1 << 40, 3 << 30]), Some(3 << 40));
+        assert_eq!(lcm_all(&[u64::MAX - 1, u64::MAX]), None);
+    }
+
+    #[test]
+    fn tests_primality() {
+        let small_primes = (0..200).filter(|n| is_prime(*n)).collect::<Vec<_>>();
+        assert_eq!(small_primes, primes_in(0..200));
+        assert!(is_prime(20201227));
+        assert!(is_prime(u64::MAX - 58));
+        // Strong pseudoprimes to several bases and a Carmichael number.
+        assert!(!is_prime(3215031751));
+        assert!(!is_prime(3825123056546413051));
+        assert!(!is_prime(561));
+        assert!(!is_prime(4294967297));
+    }
+
+    #[test]
+    fn factorizes() {
+        assert_eq!(factorize(1), vec![]);
+        assert_eq!(factorize(12), vec![(2, 2), (3, 1)]);
+        assert_eq!(
+            factorize(20201226),
+            vec![(2, 1), (3, 1), (29, 1), (116099, 1)]
+        );
+        assert_eq!(factorize(4294967297), vec![(641, 1), (6700417, 1)]);
+        assert_eq!(factorize(1 << 63), vec![(2, 63)]);
+        assert_eq!(factorize(3u64.pow(40)), vec![(3, 40)]);
+        // The product of the two largest 32-bit primes.
+        assert_eq!(
+            factorize(4294967291 * 4294967279),
+            vec![(4294967279, 1), (4294967291, 1)]
+        );
+        for n in 1..2000 {
+            let product = factorize(n)
+                .into_iter()
+                .map(|(prime, exponent)| prime.pow(exponent))
+                .product::<u64>();
+            assert_eq!(product, n);
+        }
+    }
+
+    #[test]
+    fn sieves_segments() {
+        assert_eq!(primes_in(0..30), vec![2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+        assert_eq!(primes_in(2..3), vec![2]);
+        assert_eq!(primes_in(10..10), vec![]);
+        assert_eq!(
+            primes_in(1_000_000_000..1_000_000_100),
+            vec![
+                1000000007, 1000000009, 1000000021, 1000000033, 1000000087, 1000000093, 1000000097
+            ]
+        );
+        // More than one segment.
+        let primes = primes_in(0..100_000);
+        assert_eq!(primes.len(), 9592);
+        assert!(primes.iter().all(|prime| is_prime(*prime)));
+    }
+
+    #[test]
+    fn counts_coprime_numbers() {
+        assert_eq!(totient(1), 1);
+        assert_eq!(totient(36), 12);
+        assert_eq!(totient(20201227), 20201226);
+        assert_eq!(carmichael(1), 1);
+        assert_eq!(carmichael(8), 2);
+        assert_eq!(carmichael(561), 80);
+        assert_eq!(carmichael(20201227), 20201226);
+        for n in 1..200 {
+            let coprime = (1..=n).filter(|a| gcd(*a, n) == 1).collect::<Vec<_>>();
+            assert_eq!(totient(n), coprime.len() as u64);
+            let orders = coprime.iter().map(|a| multiplicative_order(*a, n).unwrap());
+            assert_eq!(lcm_all(&orders.collect::<Vec<_>>()), Some(carmichael(n)));
+        }
+    }
+
+    #[test]
+    fn finds_multiplicative_orders() {
+        assert_eq!(multiplicative_order(7, 20201227), Some(20201226));
+        assert_eq!(multiplicative_order(2, 7), Some(3));
+        assert_eq!(multiplicative_order(6, 7), Some(2));
+        assert_eq!(multiplicative_order(1, 1), Some(1));
+        assert_eq!(multiplicative_order(4, 6), None);
+        assert_eq!(multiplicative_order(3, 0), None);
+        let brute_force = |a, n| (1..=n).find(|m| ModInt::new(a, n).pow(*m).value() == 1);
+        assert_eq!(multiplicative_order(10, 97), brute_force(10, 97));
+    }
+
+    #[test]
+    fn finds_smallest_logarithms() {
+        let brute_force = |base, target, modulus| {
+            let base = ModInt::new(base, modulus);
+            (0..2 * modulus).find(|exponent| base.pow(*exponent) == ModInt::new(target, modulus))
+        };
+        for modulus in 1..60 {
+            for base in 0..modulus {
+                for target in 0..modulus {
+                    assert_eq!(
+                        discrete_logarithm(base, target, modulus),
+                        brute_force(base, target, modulus),
+                        "{}^x = {} (mod {})",
+                        base,
+                        target,
+                        modulus
+                    );
+                }
+            }
+        }
+        assert_eq!(discrete_logarithm(6, 6, 7), Some(1));
+        assert_eq!(discrete_logarithm(7, 1, 20201227), Some(0));
+        assert_eq!(discrete_logarithm(2, 8, 5), Some(3));
+        assert_eq!(discrete_logarithm(3, 0, 7), None);
+        assert_eq!(discrete_logarithm(4, 3, 7), None);
+    }
+
+    #[test]
+    fn finds_logarithms_beyond_the_preperiod() {
+        // Powers of 2 are 0 modulo 2^10 from 2^10 on, and cycle modulo the prime after.
+        let modulus = 1000003 << 10;
+        let power = |exponent| ModInt::new(2, modulus).pow(exponent).value();
+        assert_eq!(discrete_logarithm(2, power(100), modulus), Some(100));
+        assert_eq!(discrete_logarithm(2, power(1000012), modulus), Some(10));
+        assert_eq!(discrete_logarithm(2, power(1000070), modulus), Some(68));
+        assert_eq!(discrete_logarithm(2, power(100) + 1, modulus), None);
+        assert_eq!(discrete_logarithm(4, power(120), modulus), Some(60));
+    }
+
+    #[test]
+    fn finds_logarithms_of_large_primes() {
+        // Their group orders have the prime factors 52445056723 (for baby steps) and
+        // 5594472617641 (for the rho algorithm).
+        for modulus in [1_000_000_000_000_000_003, 18446744073709551557] {
+            let base = (2..).find(|base| multiplicative_order(*base, modulus) == Some(modulus - 1));
+            let base = ModInt::new(base.unwrap(), modulus);
+            for exponent in [0, 1, 123_456_789_012, modulus - 2] {
+                let target = base.pow(exponent).value();
+                assert_eq!(
+                    discrete_logarithm(base.value(), target, modulus),
+                    Some(exponent)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn combines_logarithm_algorithms() {
+        let (base, modulus) = (ModInt::new(7, 20201227), 20201227);
+        let target = base.pow(1234567);
+        assert_eq!(baby_step_giant_step(base, target, modulus), Some(1234567));
+        assert_eq!(baby_step_giant_step(base, target, 1000), None);
+        // 2 has the prime order 23 modulo 47.
+        let base = ModInt::new(2, 47);
+        assert_eq!(multiplicative_order(2, 47), Some(23));
+        for exponent in 0..23 {
+            assert_eq!(
+                pollard_rho_logarithm(base, base.pow(exponent), 23),
+                Some(exponent)
+            );
+        }
+        assert_eq!(
+            pohlig_hellman(ModInt::new(7, 20201227), ModInt::new(5764801, 20201227)),
+            Some((8, 20201226))
+        );
+    }
 }
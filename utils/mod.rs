@@ -1,2 +1,21 @@
+pub mod alloc;
+pub mod answer;
+pub mod automaton;
+pub mod bench;
+pub mod bigint;
+pub mod config;
+pub mod fetch;
+pub mod grid;
+pub mod http;
+pub mod input;
+pub mod json;
+pub mod manifest;
 pub mod maths;
+pub mod parse;
+pub mod random;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod submit;
 pub mod unique;
@@ -0,0 +1,228 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Error of a parser, pointing at the offending text of the puzzle input.
+///
+/// Parsers create it from a slice of the text they got, so it can be located
+/// in the whole input afterwards without threading positions through them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    day: Option<(u16, u8)>,
+    location: Option<(usize, usize)>,
+    text: String,
+    reason: String,
+    address: usize,
+}
+
+impl ParseError {
+    /// `text` should be a slice of the parsed input, otherwise it can't be located.
+    pub fn new(text: &str, reason: impl Into<String>) -> Self {
+        ParseError {
+            day: None,
+            location: None,
+            text: text.to_string(),
+            reason: reason.into(),
+            address: text.as_ptr() as usize,
+        }
+    }
+
+    /// Determines line and column of the offending text, if it's a slice of `input`.
+    pub fn locate(mut self, input: &str) -> Self {
+        let start = input.as_ptr() as usize;
+        if self.location.is_none() && (start..=start + input.len()).contains(&self.address) {
+            let preceding = &input[..self.address - start];
+            let line_start = preceding.rfind('\n').map_or(0, |index| index + 1);
+            self.location = Some((
+                preceding.matches('\n').count() + 1,
+                preceding[line_start..].chars().count() + 1,
+            ));
+        }
+        self
+    }
+
+    pub fn in_day(mut self, year: u16, day: u8) -> Self {
+        self.day = Some((year, day));
+        self
+    }
+
+    /// The year and day of the puzzle input.
+    pub fn day(&self) -> Option<(u16, u8)> {
+        self.day
+    }
+
+    /// The 1-based line of the offending text.
+    pub fn line(&self) -> Option<usize> {
+        self.location.map(|(line, _column)| line)
+    }
+
+    /// The 1-based column of the offending text, counted in characters.
+    pub fn column(&self) -> Option<usize> {
+        self.location.map(|(_line, column)| column)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some((year, day)) = self.day {
+            write!(f, "day {:02} of {}, ", day, year)?;
+        }
+        if let Some((line, column)) = self.location {
+            write!(f, "line {}, column {}: ", line, column)?;
+        }
+        write!(f, "{} `{}`", self.reason, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `text` via `FromStr` and reports it as the offending text on failure.
+pub fn parse_value<T>(text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse()
+        .map_err(|err| ParseError::new(text, format!("{}", err)))
+}
+
+/// Splits `text` at the first `delimiter` or fails with the whole text.
+pub fn split_once<'a>(text: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    text.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(text, format!("Missing `{}`!", delimiter.escape_debug())))
+}
+
+/// Skips a byte order mark and trailing whitespace, which some editors add.
+fn trim_input(text: &str) -> &str {
+    text.strip_prefix('\u{feff}').unwrap_or(text).trim_end()
+}
+
+/// The lines of `text` without trailing whitespace like the `\r` of a CRLF line
+/// break, where trailing empty lines are skipped.
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    trim_input(text).lines().map(str::trim_end)
+}
+
+/// The blocks of lines separated by empty lines, where a line of whitespace is
+/// empty as well. Every block is a slice of `text` and ends without whitespace.
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    let text = trim_input(text);
+    let mut blocks = Vec::new();
+    let (mut block, mut offset) = (None, 0);
+    for line in text.split_inclusive('\n') {
+        let end = offset + line.trim_end().len();
+        if end == offset {
+            blocks.extend(block.take().map(|(start, end)| &text[start..end]));
+        } else {
+            block = Some((block.map_or(offset, |(start, _end)| start), end));
+        }
+        offset += line.len();
+    }
+    blocks.extend(block.map(|(start, end)| &text[start..end]));
+    blocks.into_iter()
+}
+
+/// The input as other platforms and editors save it, to test parsers with.
+#[cfg(test)]
+pub fn platform_variants(input: &str) -> Vec<String> {
+    let trailing_whitespace = input
+        .lines()
+        .map(|line| format!("{} \t\n", line))
+        .collect::<String>();
+    vec![
+        format!("{}\r\n", input.replace('\n', "\r\n")),
+        format!("\u{feff}{}\n\n", input),
+        trailing_whitespace + "\n",
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_slices() {
+        let input = "abc\ndef ghi\n\njkl";
+        let error = ParseError::new(&input[8..11], "Bad!").locate(input);
+        assert_eq!((error.line(), error.column()), (Some(2), Some(5)));
+        assert_eq!(error.text(), "ghi");
+
+        let error = ParseError::new(&input[14..], "Bad!").locate(input);
+        assert_eq!((error.line(), error.column()), (Some(4), Some(2)));
+
+        let error = ParseError::new(&input[input.len()..], "Missing!").locate(input);
+        assert_eq!((error.line(), error.column()), (Some(4), Some(4)));
+    }
+
+    #[test]
+    fn counts_characters_as_columns() {
+        let input = "äöü x";
+        let error = ParseError::new(&input[7..], "Bad!").locate(input);
+        assert_eq!(error.column(), Some(5));
+    }
+
+    #[test]
+    fn ignores_foreign_text() {
+        let input = "abc";
+        let foreign = "abc".repeat(2);
+        let error = ParseError::new(&foreign, "Bad!").locate(input);
+        assert_eq!(error.line(), None);
+        assert_eq!(error.to_string(), "Bad! `abcabc`");
+    }
+
+    #[test]
+    fn splits_lines() {
+        let input = "\u{feff}1 \r\n2\r\n\r\n3\t\n\n \n";
+        assert_eq!(lines(input).collect::<Vec<_>>(), vec!["1", "2", "", "3"]);
+        assert_eq!(lines("").count(), 0);
+    }
+
+    #[test]
+    fn splits_blocks() {
+        let input = "a\r\nb \r\n\r\nc\n \n\n\nd\ne\n\n";
+        assert_eq!(
+            blocks(input).collect::<Vec<_>>(),
+            vec!["a\r\nb", "c", "d\ne"]
+        );
+        assert_eq!(blocks("\n\n").count(), 0);
+
+        let error = ParseError::new(blocks(input).nth(1).unwrap(), "Bad!").locate(input);
+        assert_eq!(error.line(), Some(4));
+    }
+
+    #[test]
+    fn splits_platform_variants() {
+        let example = "1\n2\n\n3 4\n5";
+        for variant in platform_variants(example) {
+            assert_eq!(
+                lines(&variant).collect::<Vec<_>>(),
+                lines(example).collect::<Vec<_>>()
+            );
+            let blocks = blocks(&variant)
+                .map(|block| lines(block).collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            assert_eq!(blocks, vec![vec!["1", "2"], vec!["3 4", "5"]]);
+        }
+    }
+
+    #[test]
+    fn displays_location() {
+        let input = "1\n2\nx";
+        let error = parse_value::<u32>(&input[4..])
+            .unwrap_err()
+            .locate(input)
+            .in_day(2020, 9);
+        assert_eq!(
+            error.to_string(),
+            "day 09 of 2020, line 3, column 1: invalid digit found in string `x`"
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use std::ops::Range;
+
+/// A small pseudo-random number generator (SplitMix64), which always yields the same
+/// numbers for the same seed, so generated inputs can be reproduced.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A uniformly distributed number below `bound`, where numbers from the
+    /// incomplete last multiple of `bound` are rejected to avoid a bias.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "Nothing is below zero!");
+        let zone = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % bound;
+            }
+        }
+    }
+
+    /// A uniformly distributed number in the non-empty `range`.
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "Empty range!");
+        let width = range.end.abs_diff(range.start);
+        range.start.wrapping_add(self.below(width) as i64)
+    }
+
+    /// Whether an event with a probability of `numerator / denominator` happens.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.below(index as u64 + 1) as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeats_with_the_same_seed() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..5).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+        assert_eq!(numbers(2020), numbers(2020));
+        assert_ne!(numbers(2020), numbers(2021));
+        // The first output of SplitMix64 seeded with zero.
+        assert_eq!(numbers(0)[0], 0xe220_a839_7b1d_cdaf);
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!(rng.below(3) < 3);
+            assert!((-5..5).contains(&rng.range(-5..5)));
+        }
+        assert_eq!(rng.range(i64::MIN..i64::MIN + 1), i64::MIN);
+        let mut seen = [false; 6];
+        (0..100).for_each(|_| seen[rng.below(6) as usize] = true);
+        assert!(seen.iter().all(|seen| *seen));
+    }
+
+    #[test]
+    fn shuffles_permutations() {
+        let mut rng = Rng::new(1);
+        let mut items = (0..50).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..50).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..50).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,219 @@
+use super::alloc::{format_bytes, is_counting, AllocStats};
+use super::answer::Answer;
+use super::bench::Stage;
+use super::json::Json;
+use super::runner::{DayRun, Output};
+use super::solution::Day;
+use std::time::Duration;
+
+/// The allocations of every stage of the solved days.
+pub fn allocation_rows(day_runs: &[DayRun]) -> Vec<(&'static Day, Stage, AllocStats)> {
+    day_runs
+        .iter()
+        .filter_map(|day_run| Some((day_run.day, day_run.solved.as_ref().ok()?)))
+        .flat_map(|(day, solved)| {
+            let parse = (day, Stage::Parse, solved.parse.allocations);
+            let parts = solved
+                .answers
+                .iter()
+                .map(move |(part, _answer, cost)| (day, Stage::Part(*part), cost.allocations));
+            std::iter::once(parse).chain(parts)
+        })
+        .collect()
+}
+
+/// Prints a table of how often and how much every stage allocated.
+pub fn print_allocations(rows: &[(&'static Day, Stage, AllocStats)]) {
+    println!(
+        "{:<9}{:<8}{:>12}{:>12}{:>12}",
+        "day", "stage", "allocations", "bytes", "peak"
+    );
+    for (day, stage, stats) in rows {
+        println!(
+            "{:<9}{:<8}{:>12}{:>12}{:>12}",
+            day.to_string(),
+            stage.to_string(),
+            stats.allocations,
+            format_bytes(stats.bytes),
+            format_bytes(stats.peak)
+        );
+    }
+}
+
+/// Prints every answer as soon as its day is run, failures go to stderr.
+pub fn print_answers(day_runs: impl Iterator<Item = DayRun>, output: Output) -> bool {
+    let mut succeeded = true;
+    for day_run in day_runs {
+        let day = day_run.day;
+        match day_run.solved {
+            Ok(solved) => {
+                for (part, answer, _cost) in solved.answers {
+                    if let Answer::Failed(reason) = answer {
+                        eprintln!("{} part {} failed: {}", day, part.number(), reason);
+                        succeeded = false;
+                    } else if output == Output::Quiet {
+                        println!("{}", answer);
+                    } else {
+                        let description = day.descriptions[part.number() as usize - 1];
+                        println!(
+                            "{} part {}: {}",
+                            day,
+                            part.number(),
+                            answer.describe(description)
+                        );
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("{}", err);
+                succeeded = false;
+            }
+        }
+    }
+    succeeded
+}
+
+/// Prints a table of the answers and timings of every stage, followed by the failures.
+pub fn print_summary(day_runs: Vec<DayRun>, elapsed: Duration) -> bool {
+    let rows = day_runs
+        .iter()
+        .filter_map(|day_run| Some((day_run.day, day_run.solved.as_ref().ok()?)))
+        .flat_map(|(day, solved)| {
+            let parse = (day, Stage::Parse, String::new(), solved.parse.time);
+            let parts = solved.answers.iter().map(move |(part, answer, cost)| {
+                let answer = match answer {
+                    Answer::Failed(_) => "failed".to_string(),
+                    answer => answer.to_string(),
+                };
+                (day, Stage::Part(*part), answer, cost.time)
+            });
+            std::iter::once(parse).chain(parts)
+        })
+        .collect::<Vec<_>>();
+    let answer_width = rows
+        .iter()
+        .map(|(_day, _stage, answer, _time)| answer.len())
+        .chain(["answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:<9}{:<8}{:<answer_width$}{:>12}",
+        "day", "stage", "answer", "time"
+    );
+    for (day, stage, answer, time) in &rows {
+        println!(
+            "{:<9}{:<8}{:<answer_width$}{:>12}",
+            day.to_string(),
+            stage.to_string(),
+            answer,
+            format!("{:.2?}", time)
+        );
+    }
+
+    let failures = day_runs
+        .iter()
+        .map(|day_run| match &day_run.solved {
+            Ok(solved) => solved
+                .answers
+                .iter()
+                .filter_map(|(part, answer, _cost)| match answer {
+                    Answer::Failed(reason) => Some(format!(
+                        "{} part {} failed: {}",
+                        day_run.day,
+                        part.number(),
+                        reason
+                    )),
+                    _ => None,
+                })
+                .collect(),
+            Err(err) => vec![err.clone()],
+        })
+        .filter(|failures: &Vec<_>| !failures.is_empty())
+        .collect::<Vec<_>>();
+    for err in failures.iter().flatten() {
+        println!("FAIL, {}", err);
+    }
+    let total = rows
+        .iter()
+        .map(|(_day, _stage, _answer, time)| *time)
+        .sum::<Duration>();
+    println!(
+        "{} days in {:.2?} ({:.2?} summed up), {} failed",
+        day_runs.len(),
+        elapsed,
+        total,
+        failures.len()
+    );
+    failures.is_empty()
+}
+
+pub fn alloc_json(stats: AllocStats) -> Json {
+    Json::object([
+        ("count", Json::from(stats.allocations as u64)),
+        ("bytes", Json::from(stats.bytes as u64)),
+        ("peak_bytes", Json::from(stats.peak as u64)),
+    ])
+}
+
+/// Writes the answers, timings and failures of every day as one JSON document,
+/// where failing days have an `error` status instead of answers.
+pub fn print_json(day_runs: impl Iterator<Item = DayRun>) -> bool {
+    let mut succeeded = true;
+    let days = day_runs
+        .map(|day_run| {
+            let mut fields = vec![
+                ("year", Json::from(u64::from(day_run.day.year))),
+                ("day", Json::from(u64::from(day_run.day.day))),
+                ("input", Json::from(day_run.source.to_string())),
+            ];
+            match day_run.solved {
+                Ok(solved) => {
+                    let parts = solved
+                        .answers
+                        .into_iter()
+                        .map(|(part, answer, cost)| {
+                            let status = match &answer {
+                                Answer::Unsolved => "unsolved",
+                                Answer::Failed(_) => "failed",
+                                _ => "solved",
+                            };
+                            let mut part_fields = vec![
+                                ("part", Json::from(u64::from(part.number()))),
+                                ("status", Json::from(status)),
+                            ];
+                            if let Answer::Failed(reason) = &answer {
+                                succeeded = false;
+                                part_fields.push(("error", Json::from(reason.clone())));
+                            }
+                            part_fields.extend([
+                                ("answer", Json::from(answer)),
+                                ("time_ns", Json::from(cost.time.as_nanos() as u64)),
+                            ]);
+                            if is_counting() {
+                                part_fields.push(("allocations", alloc_json(cost.allocations)));
+                            }
+                            Json::object(part_fields)
+                        })
+                        .collect();
+                    fields.extend([
+                        ("status", Json::from("ok")),
+                        ("parse_ns", Json::from(solved.parse.time.as_nanos() as u64)),
+                    ]);
+                    if is_counting() {
+                        fields.push(("parse_allocations", alloc_json(solved.parse.allocations)));
+                    }
+                    fields.push(("parts", Json::Array(parts)));
+                }
+                Err(err) => {
+                    succeeded = false;
+                    fields.extend([("status", Json::from("error")), ("error", Json::from(err))]);
+                }
+            }
+            Json::object(fields)
+        })
+        .collect();
+
+    println!("{}", Json::object([("days", Json::Array(days))]).pretty());
+    succeeded
+}
@@ -0,0 +1,210 @@
+use super::alloc::enable_counting;
+use super::config::Config;
+use super::input::InputSource;
+use super::report::{allocation_rows, print_allocations, print_answers, print_json, print_summary};
+use super::solution::{Day, Part, Solved};
+use crate::all_days;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Mutex};
+use std::thread;
+use std::time::Instant;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Output {
+    /// Fills the answers into the sentences describing them.
+    Described,
+    /// Only prints the raw answers or, when verifying, the failures.
+    Quiet,
+    /// Prints one JSON document with the answers, timings and failures.
+    Json,
+}
+
+#[derive(Debug)]
+pub struct RunArgs {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: Option<InputSource>,
+    pub output: Output,
+    /// Runs the days on this many threads and prints a summary at the end.
+    pub jobs: Option<usize>,
+    /// Counts the allocations of every stage, which needs the days to run one at a time.
+    pub allocations: bool,
+}
+
+pub fn selected_parts(run_args: &RunArgs) -> Vec<Part> {
+    match run_args.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+/// The bundled input of a day or, if there's none or it's empty, the fetched one.
+pub fn default_source(day: &Day, config: &Config) -> InputSource {
+    let bundled = day.data_path();
+    if fs::metadata(&bundled).is_ok_and(|metadata| metadata.len() > 0) {
+        InputSource::Bundled(bundled)
+    } else {
+        InputSource::File(config.cached_input(day.year, day.day))
+    }
+}
+
+/// The selected days together with where their input is read from.
+pub fn selected_days<'a>(
+    run_args: &'a RunArgs,
+    config: &'a Config,
+) -> impl Iterator<Item = (&'static Day, InputSource)> + 'a {
+    all_days()
+        .filter(|day| {
+            run_args.year.is_none_or(|only_year| only_year == day.year)
+                && run_args.day.is_none_or(|only_day| only_day == day.day)
+        })
+        .map(|day| {
+            let source = run_args
+                .input
+                .clone()
+                .unwrap_or_else(|| default_source(day, config));
+            (day, source)
+        })
+}
+
+/// A day run on its input, where failing to read or parse the input is described.
+pub struct DayRun {
+    pub day: &'static Day,
+    pub source: InputSource,
+    pub solved: Result<Solved, String>,
+}
+
+/// Runs a day on its input, where a panicking day is turned into a failure.
+pub fn run_day(day: &'static Day, source: InputSource, parts: &[Part]) -> DayRun {
+    let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+        source
+            .read()
+            .map_err(|err| format!("Couldn't read input of {}: {}", day, err))
+            .and_then(|input| {
+                (day.solver)(&input, parts)
+                    .map_err(|err| format!("Couldn't parse input of {}", err))
+            })
+    }))
+    .unwrap_or_else(|_panic| Err(format!("{} panicked!", day)));
+    DayRun {
+        day,
+        source,
+        solved,
+    }
+}
+
+/// The stack of the main thread, which every day fits into, while spawned threads
+/// only get 2 MiB by default.
+const WORKER_STACK_SIZE: usize = 8 * 1024 * 1024;
+
+/// Runs the selected days on a pool of `jobs` threads and returns them ordered by day.
+pub fn run_parallel(
+    run_args: &RunArgs,
+    config: &Config,
+    parts: &[Part],
+    jobs: usize,
+) -> Vec<DayRun> {
+    let days = selected_days(run_args, config).collect::<Vec<_>>();
+    let workers = jobs.min(days.len());
+    let queue = Mutex::new(days.into_iter());
+
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        for worker in 0..workers {
+            let (queue, sender) = (&queue, sender.clone());
+            thread::Builder::new()
+                .name(format!("worker {}", worker))
+                .stack_size(WORKER_STACK_SIZE)
+                .spawn_scoped(scope, move || {
+                    while let Some((day, source)) = queue.lock().expect("Queue is poisoned!").next()
+                    {
+                        sender
+                            .send(run_day(day, source, parts))
+                            .expect("Receiver is gone!");
+                    }
+                })
+                .expect("Worker couldn't be spawned!");
+        }
+    });
+    drop(sender);
+
+    let mut day_runs = receiver.into_iter().collect::<Vec<_>>();
+    day_runs.sort_by_key(|day_run| (day_run.day.year, day_run.day.day));
+    day_runs
+}
+
+/// Runs the selected days and reports failing ones, which is returned as `false`.
+pub fn run(run_args: &RunArgs, config: &Config) -> bool {
+    let parts = selected_parts(run_args);
+    if run_args.allocations {
+        enable_counting();
+    }
+
+    let start = Instant::now();
+    let day_runs: Box<dyn Iterator<Item = DayRun>> = match run_args.jobs {
+        Some(jobs) => Box::new(run_parallel(run_args, config, &parts, jobs).into_iter()),
+        None => Box::new(
+            selected_days(run_args, config).map(|(day, source)| run_day(day, source, &parts)),
+        ),
+    };
+    match (run_args.output, run_args.jobs) {
+        (Output::Json, _) => print_json(day_runs),
+        (Output::Described, Some(_jobs)) => print_summary(day_runs.collect(), start.elapsed()),
+        (output, _) if run_args.allocations => {
+            let day_runs = day_runs.collect::<Vec<_>>();
+            let rows = allocation_rows(&day_runs);
+            let succeeded = print_answers(day_runs.into_iter(), output);
+            print_allocations(&rows);
+            succeeded
+        }
+        (output, _) => print_answers(day_runs, output),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::parse::ParseError;
+    use crate::utils::solution::{day, Solution, Unsolved};
+
+    struct Panicking;
+
+    impl Solution for Panicking {
+        const YEAR: u16 = 2020;
+        const DAY: u8 = 1;
+        const DATA_FILE: &'static str = "01_data.txt";
+        const DESCRIPTIONS: [&'static str; 2] = ["{}", "{}"];
+
+        type Input<'a> = &'a str;
+        type PartOne = u32;
+        type PartTwo = Unsolved;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input)
+        }
+
+        fn part_one(input: &Self::Input<'_>) -> u32 {
+            input.parse().expect("Not a number!")
+        }
+
+        fn part_two(_input: &Self::Input<'_>) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    static PANICKING: Day = day::<Panicking>();
+
+    #[test]
+    fn turns_panics_into_failures() {
+        let path = std::env::temp_dir().join("aoc_panicking_day.txt");
+        let solve = |input: &str| {
+            fs::write(&path, input).unwrap();
+            run_day(&PANICKING, InputSource::File(path.clone()), &Part::ALL).solved
+        };
+        assert!(solve("1").is_ok());
+        assert_eq!(solve("x").err(), Some("2020/01 panicked!".to_string()));
+        fs::remove_file(&path).unwrap();
+    }
+}
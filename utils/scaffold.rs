@@ -0,0 +1,331 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+
+/// The root of the library, where every year is registered.
+pub const LIBRARY_FILE: &str = "lib.rs";
+
+/// The skeleton of a day, where `{{YEAR}}` is the year, `{{NN}}` the zero-padded
+/// day and `{{DAY}}` the plain one.
+const TEMPLATE: &str = include_str!("../template.rs");
+
+/// The module of a year without any days.
+pub const EMPTY_YEAR: &str = "use crate::utils::solution::{day, Day};
+
+/// One line per day, so new days can be registered in order.
+#[rustfmt::skip]
+pub const DAYS: [Day; 0] = [];
+";
+
+/// The module of a year, where its days are registered.
+pub fn year_file(year: u16) -> String {
+    format!("{}/mod.rs", year)
+}
+
+pub fn day_file(year: u16, day: u8) -> String {
+    format!("{}/{:02}.rs", year, day)
+}
+
+/// The placeholder for the puzzle input, which every new day reads as text.
+pub fn data_file(year: u16, day: u8) -> String {
+    format!("{}/{:02}_data.txt", year, day)
+}
+
+pub fn render_day(year: u16, day: u8) -> String {
+    TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{NN}}", &format!("{:02}", day))
+        .replace("{{DAY}}", &day.to_string())
+}
+
+/// Parses the key out of a registration line like `#[path = "07.rs"]` or
+/// `day::<day07::Day07>(),`.
+fn registered(line: &str, prefix: &str, suffix: &str) -> Option<u16> {
+    line.trim()
+        .strip_prefix(prefix)?
+        .split_once(suffix)?
+        .0
+        .parse()
+        .ok()
+}
+
+/// Inserts the lines declaring a module before the first module with a larger
+/// key or after the last one. Without any modules, they're put before the first
+/// line starting with `fallback`.
+fn insert_module(
+    lines: &mut Vec<String>,
+    module: [String; 2],
+    key: u16,
+    parse_key: impl Fn(&str) -> Option<u16>,
+    fallback: &str,
+) -> Result<(), String> {
+    let modules = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, parse_key(line)?)))
+        .collect::<Vec<_>>();
+    if modules
+        .iter()
+        .any(|(_index, registered)| *registered == key)
+    {
+        return Err(format!("{} is already registered!", module[1]));
+    }
+    match modules
+        .iter()
+        .find(|(_index, registered)| *registered > key)
+    {
+        Some((index, _registered)) => {
+            lines.splice(index..index, module);
+        }
+        None => match modules.last() {
+            Some((index, _registered)) => {
+                lines.splice(index + 2..index + 2, module);
+            }
+            None => {
+                let index = lines
+                    .iter()
+                    .position(|line| line.starts_with(fallback))
+                    .ok_or_else(|| format!("No `{}` found!", fallback))?;
+                lines.splice(index..index, module.into_iter().chain([String::new()]));
+            }
+        },
+    }
+    Ok(())
+}
+
+/// Inserts an entry ordered by its key into the array starting at the line
+/// `header` begins with, and increments its length given at the end of `header`.
+fn insert_entry(
+    lines: &mut Vec<String>,
+    header: &str,
+    entry: String,
+    key: u16,
+    parse_key: impl Fn(&str) -> Option<u16>,
+) -> Result<(), String> {
+    let header_index = lines
+        .iter()
+        .position(|line| line.starts_with(header))
+        .ok_or_else(|| format!("No `{}` found!", header))?;
+    // An empty array is written on a single line.
+    if let Some(start) = lines[header_index].strip_suffix("[];") {
+        lines[header_index] = format!("{}[", start);
+        lines.insert(header_index + 1, "];".to_string());
+    }
+    let length = registered(&lines[header_index], header, "]")
+        .ok_or_else(|| format!("Unexpected length of `{}`!", header))?;
+    lines[header_index] = format!("{}{}] = [", header, length + 1);
+
+    let end_index = header_index
+        + lines[header_index..]
+            .iter()
+            .position(|line| line == "];")
+            .ok_or_else(|| format!("No end of `{}` found!", header))?;
+    let entry_index = (header_index + 1..end_index)
+        .find(|index| parse_key(&lines[*index]).is_some_and(|registered| registered > key))
+        .unwrap_or(end_index);
+    lines.insert(entry_index, entry);
+    Ok(())
+}
+
+/// Adds the module of a day and its entry in `DAYS` to the module of its year,
+/// both in the order of the days.
+pub fn register_day(year_module: &str, year: u16, day: u8) -> Result<String, String> {
+    let mut lines = year_module.lines().map(str::to_string).collect::<Vec<_>>();
+    insert_module(
+        &mut lines,
+        [
+            format!("#[path = \"{:02}.rs\"]", day),
+            format!("pub mod day{:02};", day),
+        ],
+        day.into(),
+        |line| registered(line, "#[path = \"", ".rs\"]"),
+        "/// One line per day",
+    )
+    .map_err(|_err| format!("Day {}/{:02} is already registered!", year, day))?;
+    insert_entry(
+        &mut lines,
+        "pub const DAYS: [Day; ",
+        format!("    day::<day{0:02}::Day{0:02}>(),", day),
+        day.into(),
+        |line| registered(line, "day::<day", "::"),
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds the module of a year and its entry in `YEARS` to the root of the library,
+/// both in the order of the years.
+pub fn register_year(library: &str, year: u16) -> Result<String, String> {
+    let mut lines = library.lines().map(str::to_string).collect::<Vec<_>>();
+    insert_module(
+        &mut lines,
+        [
+            format!("#[path = \"{}/mod.rs\"]", year),
+            format!("pub mod y{};", year),
+        ],
+        year,
+        |line| registered(line, "#[path = \"", "/mod.rs\"]"),
+        "use ",
+    )
+    .map_err(|_err| format!("Year {} is already registered!", year))?;
+    insert_entry(
+        &mut lines,
+        "pub const YEARS: [&[Day]; ",
+        format!("    &y{}::DAYS,", year),
+        year,
+        |line| registered(line, "&y", "::"),
+    )?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates a file, which mustn't exist yet.
+pub fn create_new(path: &str, content: &str) -> io::Result<()> {
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .and_then(|mut file| file.write_all(content.as_bytes()))
+        .map_err(|err| io::Error::new(err.kind(), format!("{}: {}", path, err)))
+}
+
+/// Generates a day from the template with an empty input and registers it in
+/// the module of its year, which is created and registered in the library first
+/// for a new year. An existing day is never overwritten.
+pub fn new_day(year: u16, day: u8) -> bool {
+    let read = |path: &str| {
+        fs::read_to_string(path).map_err(|err| format!("Couldn't read {}: {}", path, err))
+    };
+    let scaffold = || -> Result<(), String> {
+        let is_new_year = !fs::exists(year_file(year)).unwrap_or(false);
+        let library = if is_new_year {
+            Some(register_year(&read(LIBRARY_FILE)?, year)?)
+        } else {
+            None
+        };
+        let year_module = if is_new_year {
+            register_day(EMPTY_YEAR, year, day)?
+        } else {
+            register_day(&read(&year_file(year))?, year, day)?
+        };
+        for path in [day_file(year, day), data_file(year, day)] {
+            if fs::exists(&path).unwrap_or(true) {
+                return Err(format!("{} already exists!", path));
+            }
+        }
+
+        fs::create_dir_all(year.to_string())
+            .and_then(|()| create_new(&day_file(year, day), &render_day(year, day)))
+            .and_then(|()| create_new(&data_file(year, day), ""))
+            .and_then(|()| fs::write(year_file(year), year_module))
+            .and_then(|()| library.map_or(Ok(()), |library| fs::write(LIBRARY_FILE, library)))
+            .map_err(|err| format!("Couldn't create day {}/{:02}: {}", year, day, err))
+    };
+
+    match scaffold() {
+        Ok(()) => {
+            println!(
+                "Created {} and {}, rebuild to run day {}/{:02}.",
+                day_file(year, day),
+                data_file(year, day),
+                year,
+                day
+            );
+            true
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YEAR_MODULE: &str = "use crate::utils::solution::{day, Day};
+
+#[path = \"01.rs\"]
+pub mod day01;
+#[path = \"03.rs\"]
+pub mod day03;
+
+/// One line per day, so new days can be registered in order.
+#[rustfmt::skip]
+pub const DAYS: [Day; 2] = [
+    day::<day01::Day01>(),
+    day::<day03::Day03>(),
+];
+";
+
+    #[test]
+    fn renders_template() {
+        let source = render_day(2021, 7);
+        assert!(source.contains("pub struct Day07;"));
+        assert!(source.contains("const YEAR: u16 = 2021;"));
+        assert!(source.contains("const DAY: u8 = 7;"));
+        assert!(source.contains("\"07_data.txt\""));
+        assert!(!source.contains("{{"));
+        assert_eq!(data_file(2021, 7), "2021/07_data.txt");
+    }
+
+    #[test]
+    fn registers_days_in_order() {
+        assert_eq!(
+            register_day(YEAR_MODULE, 2020, 2).unwrap(),
+            YEAR_MODULE
+                .replace(
+                    "#[path = \"03.rs\"]",
+                    "#[path = \"02.rs\"]\npub mod day02;\n#[path = \"03.rs\"]"
+                )
+                .replace("[Day; 2]", "[Day; 3]")
+                .replace(
+                    "    day::<day03",
+                    "    day::<day02::Day02>(),\n    day::<day03"
+                )
+        );
+        assert_eq!(
+            register_day(YEAR_MODULE, 2020, 25).unwrap(),
+            YEAR_MODULE
+                .replace(
+                    "pub mod day03;",
+                    "pub mod day03;\n#[path = \"25.rs\"]\npub mod day25;"
+                )
+                .replace("[Day; 2]", "[Day; 3]")
+                .replace("Day03>(),", "Day03>(),\n    day::<day25::Day25>(),")
+        );
+    }
+
+    #[test]
+    fn registers_first_day_of_year() {
+        assert_eq!(
+            register_day(EMPTY_YEAR, 2021, 1).unwrap(),
+            "use crate::utils::solution::{day, Day};
+
+#[path = \"01.rs\"]
+pub mod day01;
+
+/// One line per day, so new days can be registered in order.
+#[rustfmt::skip]
+pub const DAYS: [Day; 1] = [
+    day::<day01::Day01>(),
+];
+"
+        );
+    }
+
+    #[test]
+    fn registers_years_in_order() {
+        let library = register_year(include_str!("../lib.rs"), 2021).unwrap();
+        assert!(library.contains(
+            "#[path = \"2020/mod.rs\"]\npub mod y2020;\n#[path = \"2021/mod.rs\"]\npub mod y2021;\n"
+        ));
+        assert!(library.contains("[&[Day]; 2] = [\n    &y2020::DAYS,\n    &y2021::DAYS,\n];"));
+    }
+
+    #[test]
+    fn refuses_registered_days() {
+        assert!(register_day(YEAR_MODULE, 2020, 3).is_err());
+        assert!(register_day(include_str!("../2020/mod.rs"), 2020, 7).is_err());
+        assert!(register_day(include_str!("../2020/mod.rs"), 2020, 21).is_ok());
+        assert!(register_year(include_str!("../lib.rs"), 2020).is_err());
+    }
+}
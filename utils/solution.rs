@@ -0,0 +1,171 @@
+use super::alloc::{count_allocations, AllocStats};
+use super::answer::Answer;
+use super::bench::{measure, BenchOptions, Stage, Stats};
+use super::parse::ParseError;
+use super::random::Rng;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(number: u8) -> Option<Self> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// The answer of a part, which isn't solved (yet).
+#[derive(Debug)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+/// What a step of solving a day took.
+#[derive(Clone, Copy, Debug)]
+pub struct Cost {
+    pub time: Duration,
+    /// All zero, unless counting allocations is enabled.
+    pub allocations: AllocStats,
+}
+
+fn measure_cost<T>(routine: impl FnOnce() -> T) -> (T, Cost) {
+    let start = Instant::now();
+    let (result, allocations) = count_allocations(routine);
+    let cost = Cost {
+        time: start.elapsed(),
+        allocations,
+    };
+    (result, cost)
+}
+
+/// The answers of a day together with the cost of each step.
+#[derive(Debug)]
+pub struct Solved {
+    pub parse: Cost,
+    pub answers: Vec<(Part, Answer, Cost)>,
+}
+
+/// A day of the Advent of Code with its two parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    /// The bundled puzzle input in the directory of the year, which is used if
+    /// no other input is given.
+    const DATA_FILE: &'static str;
+    /// Sentences describing the answers of both parts, where `{}` is the answer.
+    const DESCRIPTIONS: [&'static str; 2];
+    /// Writes a random input of about `size` lines, items or the like, which
+    /// `bench --generate` times and tests can solve.
+    const GENERATOR: Option<Generator> = None;
+
+    type Input<'a>;
+    type PartOne: Into<Answer>;
+    type PartTwo: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part_one(input: &Self::Input<'_>) -> Self::PartOne;
+
+    fn part_two(input: &Self::Input<'_>) -> Self::PartTwo;
+
+    /// Parses the input once and solves the requested parts on it.
+    fn solve(input: &str, parts: &[Part]) -> Result<Solved, ParseError> {
+        let (parsed, parse) = measure_cost(|| Self::parse(input));
+        let parsed = parsed.map_err(|err| err.locate(input).in_day(Self::YEAR, Self::DAY))?;
+
+        let answers = parts
+            .iter()
+            .map(|part| {
+                let (answer, cost) = measure_cost(|| match part {
+                    Part::One => Self::part_one(&parsed).into(),
+                    Part::Two => Self::part_two(&parsed).into(),
+                });
+                (*part, answer, cost)
+            })
+            .collect();
+        Ok(Solved { parse, answers })
+    }
+
+    /// Times parsing and the requested parts on their own, each repeatedly.
+    fn bench(
+        input: &str,
+        parts: &[Part],
+        options: &BenchOptions,
+    ) -> Result<Vec<(Stage, Stats)>, ParseError> {
+        let parsed =
+            Self::parse(input).map_err(|err| err.locate(input).in_day(Self::YEAR, Self::DAY))?;
+
+        let mut timings = vec![(Stage::Parse, measure(options, || Self::parse(input)))];
+        timings.extend(parts.iter().map(|part| {
+            let stats = match part {
+                Part::One => measure(options, || Self::part_one(&parsed)),
+                Part::Two => measure(options, || Self::part_two(&parsed)),
+            };
+            (Stage::Part(*part), stats)
+        }));
+        Ok(timings)
+    }
+}
+
+pub type Solver = fn(&str, &[Part]) -> Result<Solved, ParseError>;
+pub type Bencher = fn(&str, &[Part], &BenchOptions) -> Result<Vec<(Stage, Stats)>, ParseError>;
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// A solution with its type erased, so the days of every year can be listed together.
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    pub data_file: &'static str,
+    pub descriptions: [&'static str; 2],
+    pub solver: Solver,
+    pub bencher: Bencher,
+    pub generator: Option<Generator>,
+}
+
+impl Day {
+    /// The bundled puzzle input, relative to the working directory.
+    pub fn data_path(&self) -> PathBuf {
+        Path::new(&self.year.to_string()).join(self.data_file)
+    }
+}
+
+/// Written as `<year>/<day>`, e.g. `2020/07`.
+impl Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{:02}", self.year, self.day)
+    }
+}
+
+pub const fn day<S: Solution>() -> Day {
+    Day {
+        year: S::YEAR,
+        day: S::DAY,
+        data_file: S::DATA_FILE,
+        descriptions: S::DESCRIPTIONS,
+        solver: S::solve,
+        bencher: S::bench,
+        generator: S::GENERATOR,
+    }
+}
@@ -0,0 +1,513 @@
+use super::answer::Answer;
+use super::bigint::BigInt;
+use super::config::Config;
+use super::http;
+use super::parse::{parse_value, ParseError};
+use super::runner::{default_source, run_day};
+use super::solution::{Day, Part};
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The file in the cache directory, where every submitted answer is recorded.
+pub const HISTORY_FILE: &str = "submissions.txt";
+
+/// How long the server makes you wait after a wrong answer.
+const WRONG_ANSWER_WAIT: Duration = Duration::from_secs(60);
+
+/// How the server judged a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer wasn't judged, as the last one was submitted too recently.
+    RateLimited(Duration),
+    /// The answer wasn't judged, as the part is already solved or still locked.
+    NotJudged,
+}
+
+impl Verdict {
+    /// Recognizes the verdict in the page answering a submission.
+    pub fn from_response(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Right)
+        } else if page.contains("That's not the right answer") {
+            Some(if page.contains("too high") {
+                Verdict::TooHigh
+            } else if page.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            Some(Verdict::RateLimited(
+                parse_wait(page).unwrap_or(WRONG_ANSWER_WAIT),
+            ))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Some(Verdict::NotJudged)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the server judged the answer at all, rather than turning it away.
+    pub fn is_judged(&self) -> bool {
+        !matches!(self, Verdict::RateLimited(_) | Verdict::NotJudged)
+    }
+
+    /// Whether the answer was judged to be wrong, so it's never right later on.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    /// How long the server refuses further answers after this one.
+    pub fn wait(&self) -> Duration {
+        match self {
+            Verdict::RateLimited(wait) => *wait,
+            verdict if verdict.is_wrong() => WRONG_ANSWER_WAIT,
+            _verdict => Duration::ZERO,
+        }
+    }
+}
+
+/// Parses the time left of a message like `You have 1m 5s left to wait.`
+fn parse_wait(page: &str) -> Option<Duration> {
+    let (_before, rest) = page.split_once("You have ")?;
+    let (time, _after) = rest.split_once(" left to wait")?;
+    time.split_whitespace()
+        .map(
+            |amount| match amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?) {
+                (minutes, "m") => Some(minutes.parse::<u64>().ok()? * 60),
+                (seconds, "s") => seconds.parse().ok(),
+                _amount => None,
+            },
+        )
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+/// Written like `rate-limited:65`, where the number is the wait in seconds.
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::RateLimited(wait) => write!(f, "rate-limited:{}", wait.as_secs()),
+            Verdict::NotJudged => write!(f, "not-judged"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "right" => Ok(Verdict::Right),
+            "wrong" => Ok(Verdict::Wrong),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "not-judged" => Ok(Verdict::NotJudged),
+            verdict => match verdict.strip_prefix("rate-limited:") {
+                Some(wait) => Ok(Verdict::RateLimited(Duration::from_secs(parse_value(
+                    wait,
+                )?))),
+                None => Err(ParseError::new(verdict, "Unknown verdict!")),
+            },
+        }
+    }
+}
+
+/// A submitted answer, where the time is given in seconds since the Unix epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Attempt {
+    pub time: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Written as a line of the history, `<time> <year> <day> <part> <verdict> <answer>`.
+impl Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {:02} {} {} {}",
+            self.time,
+            self.year,
+            self.day,
+            self.part.number(),
+            self.verdict,
+            self.answer
+        )
+    }
+}
+
+/// Every answer submitted so far, which tells what mustn't be submitted again.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Parses lines of attempts, where the answer is the rest of the line.
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        let attempts = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let mut fields = line.splitn(6, ' ');
+                let mut next_field = || {
+                    fields
+                        .next()
+                        .filter(|field| !field.is_empty())
+                        .ok_or_else(|| {
+                            ParseError::new(
+                                line,
+                                "Expected `<time> <year> <day> <part> <verdict> <answer>`!",
+                            )
+                        })
+                };
+
+                let time = parse_value(next_field()?)?;
+                let year = parse_value(next_field()?)?;
+                let day = parse_value(next_field()?)?;
+                let part_field = next_field()?;
+                let part = parse_value(part_field)
+                    .ok()
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| ParseError::new(part_field, "Unknown part!"))?;
+                let verdict = next_field()?.parse()?;
+                let answer = next_field()?.to_string();
+                Ok(Attempt {
+                    time,
+                    year,
+                    day,
+                    part,
+                    verdict,
+                    answer,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(History { attempts })
+    }
+
+    /// Explains why submitting the answer at the time `now` is pointless: the part
+    /// is solved already, the answer is known to be wrong or out of the learned
+    /// bounds, or the server still refuses answers.
+    pub fn check(
+        &self,
+        (year, day, part): (u16, u8, Part),
+        answer: &Answer,
+        now: u64,
+    ) -> Result<(), String> {
+        let answer_text = answer.to_string();
+        let number = match answer {
+            Answer::Integer(value) => Some(BigInt::from(*value)),
+            Answer::BigInteger(value) => Some(value.clone()),
+            Answer::Text(_) | Answer::Unsolved | Answer::Failed(_) => None,
+        };
+
+        for attempt in self
+            .attempts
+            .iter()
+            .filter(|attempt| (attempt.year, attempt.day, attempt.part) == (year, day, part))
+        {
+            let bound = number.as_ref().zip(attempt.answer.parse::<BigInt>().ok());
+            match attempt.verdict {
+                Verdict::Right => {
+                    return Err(format!("The part was solved with {}!", attempt.answer));
+                }
+                verdict if verdict.is_wrong() && attempt.answer == answer_text => {
+                    return Err(format!("{} was judged {} before!", answer_text, verdict));
+                }
+                Verdict::TooHigh
+                    if bound.as_ref().is_some_and(|(number, high)| *number >= high) =>
+                {
+                    return Err(format!(
+                        "{} isn't below {}, which is too high!",
+                        answer_text, attempt.answer
+                    ));
+                }
+                Verdict::TooLow if bound.as_ref().is_some_and(|(number, low)| *number <= low) => {
+                    return Err(format!(
+                        "{} isn't above {}, which is too low!",
+                        answer_text, attempt.answer
+                    ));
+                }
+                _verdict => {}
+            }
+        }
+
+        let allowed = self
+            .attempts
+            .iter()
+            .map(|attempt| attempt.time + attempt.verdict.wait().as_secs())
+            .max()
+            .unwrap_or_default();
+        if now < allowed {
+            return Err(format!("Wait {}s before submitting again!", allowed - now));
+        }
+        Ok(())
+    }
+}
+
+pub struct SubmitArgs {
+    pub day: &'static Day,
+    pub part: Part,
+}
+
+/// Reads the attempts submitted so far, where a missing file means there are none.
+pub fn load_history(path: &Path) -> Result<History, String> {
+    match fs::read_to_string(path) {
+        Ok(history) => History::parse(&history).map_err(|err| {
+            format!(
+                "Couldn't parse {}, {}",
+                path.display(),
+                err.locate(&history)
+            )
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+        Err(err) => Err(format!("Couldn't read {}: {}", path.display(), err)),
+    }
+}
+
+/// Solves a part and submits its answer, unless the history tells it's pointless.
+/// Only judged attempts are recorded, success means the answer is right.
+pub fn submit(submit_args: &SubmitArgs, config: &Config) -> bool {
+    let (day, part) = (submit_args.day, submit_args.part);
+    let history_path = config.cache_dir.join(HISTORY_FILE);
+    let submission = || -> Result<(Answer, Verdict), String> {
+        let cookie = config.session_cookie()?;
+        let history = load_history(&history_path)?;
+        let answer = run_day(day, default_source(day, config), &[part])
+            .solved?
+            .answers
+            .into_iter()
+            .map(|(_part, answer, _cost)| answer)
+            .next()
+            .expect("No answer!");
+        match &answer {
+            Answer::Unsolved => {
+                return Err(format!("{} part {} isn't solved!", day, part.number()));
+            }
+            Answer::Failed(reason) => {
+                return Err(format!("{} part {} failed: {}", day, part.number(), reason));
+            }
+            _answer => {}
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |time| time.as_secs());
+        history.check((day.year, day.day, part), &answer, now)?;
+
+        println!("{} part {}: submitting {}", day, part.number(), answer);
+        let response = http::post(
+            &config.answer_url(day.year, day.day)?,
+            &[("Cookie", &cookie)],
+            &format!(
+                "level={}&answer={}",
+                part.number(),
+                http::form_encode(&answer.to_string())
+            ),
+        )
+        .map_err(|err| format!("Request failed: {}", err))?;
+        let verdict = Verdict::from_response(&response.body).ok_or_else(|| {
+            format!(
+                "Server answered {} {} without a verdict!",
+                response.status, response.reason
+            )
+        })?;
+
+        if !verdict.is_judged() {
+            return Ok((answer, verdict));
+        }
+        let attempt = Attempt {
+            time: now,
+            year: day.year,
+            day: day.day,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        };
+        fs::create_dir_all(&config.cache_dir)
+            .and_then(|()| {
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&history_path)
+            })
+            .and_then(|mut file| writeln!(file, "{}", attempt))
+            .map_err(|err| format!("Couldn't record in {}: {}", history_path.display(), err))?;
+        Ok((answer, verdict))
+    };
+
+    match submission() {
+        Ok((answer, verdict)) => {
+            match verdict {
+                Verdict::Right => println!("{} is right!", answer),
+                Verdict::Wrong => println!("{} is wrong.", answer),
+                Verdict::TooHigh => println!("{} is too high.", answer),
+                Verdict::TooLow => println!("{} is too low.", answer),
+                Verdict::RateLimited(wait) => {
+                    println!("Answered too recently, wait {}s.", wait.as_secs())
+                }
+                Verdict::NotJudged => println!("The part is already solved or still locked."),
+            }
+            verdict == Verdict::Right
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(time: u64, part: Part, verdict: Verdict, answer: &str) -> Attempt {
+        Attempt {
+            time,
+            year: 2020,
+            day: 13,
+            part,
+            verdict,
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn recognizes_verdicts() {
+        let page =
+            |message: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", message);
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Some(Verdict::Right)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too high. Please wait one minute."
+            )),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::from_response(&page("That's not the right answer. If you're stuck, ...")),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "You gave an answer too recently. You have 1m 5s left to wait."
+            )),
+            Some(Verdict::RateLimited(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            Verdict::from_response(&page("You don't seem to be solving the right level.")),
+            Some(Verdict::NotJudged)
+        );
+        assert_eq!(Verdict::from_response("<html></html>"), None);
+    }
+
+    #[test]
+    fn judges_only_answers_let_through() {
+        assert!(Verdict::Right.is_judged());
+        assert!(Verdict::TooLow.is_judged());
+        assert!(!Verdict::RateLimited(Duration::from_secs(65)).is_judged());
+        assert!(!Verdict::NotJudged.is_judged());
+    }
+
+    #[test]
+    fn records_attempts() {
+        let history = "1700000000 2020 13 2 too-high 600691418730600\n\
+                       1700000100 2020 13 2 rate-limited:42 600691418730595\n\
+                       1700000200 2020 21 1 wrong mxmx, sqjhc\n";
+        let parsed = History::parse(history).unwrap();
+        assert_eq!(parsed.attempts.len(), 3);
+        assert_eq!(
+            parsed.attempts[1].verdict,
+            Verdict::RateLimited(Duration::from_secs(42))
+        );
+        assert_eq!(parsed.attempts[2].answer, "mxmx, sqjhc");
+        let written = parsed
+            .attempts
+            .iter()
+            .map(|attempt| format!("{}\n", attempt))
+            .collect::<String>();
+        assert_eq!(written, history);
+
+        let history = "1700000000 2020 13 3 wrong 1";
+        let error = History::parse(history).unwrap_err().locate(history);
+        assert_eq!(error.column(), Some(20));
+    }
+
+    #[test]
+    fn refuses_known_answers() {
+        let history = History {
+            attempts: vec![
+                attempt(0, Part::One, Verdict::Right, "410"),
+                attempt(0, Part::Two, Verdict::TooHigh, "1000"),
+                attempt(0, Part::Two, Verdict::TooLow, "100"),
+                attempt(0, Part::Two, Verdict::Wrong, "500"),
+            ],
+        };
+        let puzzle = |part| (2020, 13, part);
+        assert!(history
+            .check(puzzle(Part::One), &Answer::from(411), 100)
+            .is_err());
+        assert!(history
+            .check(puzzle(Part::Two), &Answer::from(500), 100)
+            .is_err());
+        assert!(history
+            .check(puzzle(Part::Two), &Answer::from(1000), 100)
+            .is_err());
+        assert!(history
+            .check(puzzle(Part::Two), &Answer::from(1200), 100)
+            .is_err());
+        assert!(history
+            .check(puzzle(Part::Two), &Answer::from(100), 100)
+            .is_err());
+        assert!(history
+            .check(puzzle(Part::Two), &Answer::from(501), 100)
+            .is_ok());
+        assert!(history
+            .check((2020, 14, Part::Two), &Answer::from(1200), 100)
+            .is_ok());
+    }
+
+    #[test]
+    fn waits_after_wrong_answers() {
+        let history = History {
+            attempts: vec![
+                attempt(1000, Part::Two, Verdict::Wrong, "500"),
+                attempt(
+                    1030,
+                    Part::Two,
+                    Verdict::RateLimited(Duration::from_secs(50)),
+                    "7",
+                ),
+            ],
+        };
+        let puzzle = (2020, 13, Part::Two);
+        assert!(history.check(puzzle, &Answer::from(7), 1060).is_err());
+        assert!(history.check(puzzle, &Answer::from(7), 1080).is_ok());
+        assert!(history.check(puzzle, &Answer::from("abc"), 1080).is_ok());
+    }
+}
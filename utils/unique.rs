@@ -16,7 +16,7 @@ where
     type Item = I::Item;
 
     fn next(&mut self) -> Option<Self::Item> {
-        while let Some(x) = self.underlying.next() {
+        for x in self.underlying.by_ref() {
             if !self.seen.contains(&x) {
                 self.seen.insert(x.clone());
                 return Some(x);